@@ -0,0 +1,57 @@
+use computational_geometry::convex_hull::{
+    AklToussaint, ChanHull, ConvexHullComputer, IncrementalHull, MonotoneChain,
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+mod inputs;
+
+use inputs::Distribution;
+
+
+const SEED: u64 = 0;
+
+
+fn sizes(distribution: Distribution) -> &'static [usize] {
+    // Every circle point is on the hull and building the output polygon
+    // validates it in time quadratic in the hull size, which would swamp
+    // the algorithms themselves for large circles
+    match distribution {
+        Distribution::Circle => &[100, 1_000],
+        _ => &[100, 1_000, 10_000, 100_000],
+    }
+}
+
+
+fn bench_convex_hull(c: &mut Criterion) {
+    let computers: Vec<(&str, Box<dyn ConvexHullComputer>)> = vec![
+        ("monotone_chain", Box::new(MonotoneChain)),
+        ("chan", Box::new(ChanHull)),
+        ("akl_toussaint", Box::new(AklToussaint::new(Box::new(MonotoneChain)))),
+    ];
+
+    for distribution in Distribution::ALL {
+        let mut group = c.benchmark_group(format!("convex_hull/{}", distribution.name()));
+        for &n in sizes(distribution) {
+            let points = distribution.points(n, SEED);
+            for (name, computer) in computers.iter() {
+                group.bench_with_input(BenchmarkId::new(*name, n), &points, |b, points| {
+                    b.iter(|| computer.convex_hull_of_points(points))
+                });
+            }
+            group.bench_with_input(BenchmarkId::new("incremental", n), &points, |b, points| {
+                b.iter(|| {
+                    let mut hull = IncrementalHull::new();
+                    for p in points.iter() {
+                        hull.insert(p.clone());
+                    }
+                    hull.hull()
+                })
+            });
+        }
+        group.finish();
+    }
+}
+
+
+criterion_group!(benches, bench_convex_hull);
+criterion_main!(benches);
@@ -0,0 +1,79 @@
+use std::f64::consts::TAU;
+
+use computational_geometry::point::Point;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+// Seeded point set generators shared by the benchmarks, so every run
+// measures exactly the same inputs. All points are within the square of
+// half-width `RADIUS` centered on the origin.
+
+
+pub const RADIUS: f64 = 100.0;
+
+
+#[derive(Clone, Copy, Debug)]
+pub enum Distribution {
+    // Uniform in the square, hull size grows as O(log n)
+    Square,
+    // Uniform in the disk, hull size grows as O(n^(1/3))
+    Disk,
+    // Evenly spaced on the circle so every point is on the hull
+    Circle,
+}
+
+impl Distribution {
+    pub const ALL: [Distribution; 3] = [Distribution::Square, Distribution::Disk, Distribution::Circle];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Distribution::Square => "square",
+            Distribution::Disk => "disk",
+            Distribution::Circle => "circle",
+        }
+    }
+
+    pub fn points(&self, n: usize, seed: u64) -> Vec<Point> {
+        match self {
+            Distribution::Square => random_square(n, seed),
+            Distribution::Disk => random_disk(n, seed),
+            Distribution::Circle => circle(n, seed),
+        }
+    }
+}
+
+
+pub fn random_square(n: usize, seed: u64) -> Vec<Point> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..n)
+        .map(|_| Point::new(rng.gen_range(-RADIUS..RADIUS), rng.gen_range(-RADIUS..RADIUS)))
+        .collect()
+}
+
+pub fn random_disk(n: usize, seed: u64) -> Vec<Point> {
+    // Square root of the radius keeps the density uniform over the disk
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..n)
+        .map(|_| {
+            let r = RADIUS * rng.gen::<f64>().sqrt();
+            let theta = rng.gen_range(0.0..TAU);
+            Point::new(r * theta.cos(), r * theta.sin())
+        })
+        .collect()
+}
+
+pub fn circle(n: usize, seed: u64) -> Vec<Point> {
+    // Seed only sets the starting angle, the points are then shuffled so
+    // algorithms don't get them already in hull order
+    let mut rng = StdRng::seed_from_u64(seed);
+    let offset = rng.gen_range(0.0..TAU);
+    let mut points = (0..n)
+        .map(|i| {
+            let theta = offset + TAU * i as f64 / n as f64;
+            Point::new(RADIUS * theta.cos(), RADIUS * theta.sin())
+        })
+        .collect::<Vec<_>>();
+    for i in (1..points.len()).rev() {
+        points.swap(i, rng.gen_range(0..=i));
+    }
+    points
+}
@@ -1,9 +1,12 @@
 use std::fs;
 use std::path::PathBuf;
 
-use computational_geometry::polygon::Polygon;
+use computational_geometry::{
+    geometry::Geometry,
+    polygon::{Polygon, PolygonError},
+};
 
-fn main() {
+fn main() -> Result<(), PolygonError> {
     let mut dataset_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     dataset_path.push("resources/test/interesting_polygon_archive");
 
@@ -15,12 +18,12 @@ fn main() {
     let mut vis_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     vis_path.push("../visualizer/polygons");
 
-    for path in fs::read_dir(orig_path).unwrap() {
-        let src_json_path = path.unwrap().path();
+    for path in fs::read_dir(orig_path)? {
+        let src_json_path = path?.path();
         let mut dest_json_path = dataset_path.clone();
         dest_json_path.push(src_json_path.file_name().unwrap());
 
-        let mut polygon = Polygon::from_json(&src_json_path);
+        let mut polygon = Polygon::from_json(&src_json_path)?;
         
         // Get a (rounded) bounding box center for translation vector
         let orig_bb = polygon.bounding_box();
@@ -39,11 +42,12 @@ fn main() {
         let y = orig_bb_center.y - new_bb_center.y;
         polygon.translate(x, y);
 
-        polygon.to_json(dest_json_path);
+        polygon.to_json(dest_json_path)?;
 
         // TODO will remove this when paths are resolved for visualizer
         let mut vis_json_path = vis_path.clone();
         vis_json_path.push(src_json_path.file_name().unwrap());
-        polygon.to_json(vis_json_path);
+        polygon.to_json(vis_json_path)?;
     }
+    Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,413 @@
+use crate::{
+    geometry::Geometry,
+    line_segment::{IntersectionKind, LineSegment},
+    point::Point,
+    polygon::{Orientation, Polygon},
+};
+
+// Boolean operations on simple (possibly non-convex) polygons following
+// the Weiler-Atherton approach as formulated by Greiner and Hormann.
+// Both boundaries are split at their crossings and each crossing is
+// marked as entering or exiting the other polygon. Result boundaries
+// are traced by walking along one polygon and switching to the other at
+// every crossing, where the entry flags decide the walking direction.
+//
+// Polygons are assumed to be in general position with respect to each
+// other, meaning no vertex of one lies on the boundary of the other and
+// no edges overlap. Rings that are holes in a result come back CW while
+// all other result polygons are CCW.
+
+
+#[derive(Clone, Copy, PartialEq)]
+enum Operation {
+    Intersection,
+    Union,
+    Difference,
+}
+
+
+struct Crossing {
+    point: Point,
+    // Edge index and parameter along the edge for each polygon
+    a: (usize, f64),
+    b: (usize, f64),
+}
+
+
+struct Node {
+    point: Point,
+    crossing: Option<usize>,
+    entry: bool,
+}
+
+
+impl Polygon {
+    pub fn intersection(&self, other: &Polygon) -> Vec<Polygon> {
+        boolean(self, other, Operation::Intersection)
+    }
+
+    pub fn union(&self, other: &Polygon) -> Vec<Polygon> {
+        boolean(self, other, Operation::Union)
+    }
+
+    pub fn difference(&self, other: &Polygon) -> Vec<Polygon> {
+        boolean(self, other, Operation::Difference)
+    }
+}
+
+
+fn ccw_points(polygon: &Polygon) -> Vec<Point> {
+    let mut points = polygon.boundary_vertices()
+        .iter()
+        .map(|v| v.point().clone())
+        .collect::<Vec<_>>();
+    if polygon.orientation() == Orientation::Clockwise {
+        points.reverse();
+    }
+    points
+}
+
+fn ccw_polygon(polygon: &Polygon) -> Polygon {
+    Polygon::new(ccw_points(polygon))
+}
+
+fn cw_polygon(polygon: &Polygon) -> Polygon {
+    let mut points = ccw_points(polygon);
+    points.reverse();
+    Polygon::new(points)
+}
+
+fn edges(points: &[Point]) -> Vec<LineSegment<'_>> {
+    (0..points.len())
+        .map(|i| LineSegment::new(&points[i], &points[(i + 1) % points.len()]))
+        .collect()
+}
+
+fn parameter(e: &LineSegment, p: &Point) -> f64 {
+    e.p1.distance_to(p) / e.length()
+}
+
+fn crossings(a: &[Point], b: &[Point]) -> Vec<Crossing> {
+    let mut crossings = Vec::new();
+    let b_edges = edges(b);
+    for (i, ea) in edges(a).iter().enumerate() {
+        for (j, eb) in b_edges.iter().enumerate() {
+            if let IntersectionKind::Proper(p) = ea.classify_intersection(eb) {
+                let (ta, tb) = (parameter(ea, &p), parameter(eb, &p));
+                crossings.push(Crossing { point: p, a: (i, ta), b: (j, tb) });
+            }
+        }
+    }
+    crossings
+}
+
+fn nodes(
+    points: &[Point],
+    crossings: &[Crossing],
+    edge_of: fn(&Crossing) -> (usize, f64),
+    other: &Polygon,
+) -> (Vec<Node>, Vec<usize>) {
+    // Boundary with the crossings inserted in order along each edge,
+    // along with the position of each crossing in the list
+    let mut nodes = Vec::new();
+    let mut positions = vec![0; crossings.len()];
+    for (i, p) in points.iter().enumerate() {
+        nodes.push(Node { point: p.clone(), crossing: None, entry: false });
+        let mut on_edge = (0..crossings.len())
+            .filter(|&k| edge_of(&crossings[k]).0 == i)
+            .collect::<Vec<_>>();
+        on_edge.sort_by(|&k1, &k2| edge_of(&crossings[k1]).1.total_cmp(&edge_of(&crossings[k2]).1));
+        for k in on_edge {
+            positions[k] = nodes.len();
+            nodes.push(Node { point: crossings[k].point.clone(), crossing: Some(k), entry: false });
+        }
+    }
+
+    // Crossings alternate between entering and exiting the other polygon
+    let mut inside = other.contains_point(&points[0]);
+    for node in nodes.iter_mut().filter(|n| n.crossing.is_some()) {
+        node.entry = !inside;
+        inside = !inside;
+    }
+    (nodes, positions)
+}
+
+fn boolean(a: &Polygon, b: &Polygon, operation: Operation) -> Vec<Polygon> {
+    let (a_points, b_points) = (ccw_points(a), ccw_points(b));
+    let crossings = crossings(&a_points, &b_points);
+    if crossings.is_empty() {
+        return without_crossings(a, b, operation);
+    }
+
+    let (mut a_nodes, a_positions) = nodes(&a_points, &crossings, |c| c.a, b);
+    let (mut b_nodes, b_positions) = nodes(&b_points, &crossings, |c| c.b, a);
+    // Union walks the outside of both polygons and difference walks the
+    // outside of the first and the inside of the second
+    let (flip_a, flip_b) = match operation {
+        Operation::Intersection => (false, false),
+        Operation::Union => (true, true),
+        Operation::Difference => (true, false),
+    };
+    for (nodes, flip) in [(&mut a_nodes, flip_a), (&mut b_nodes, flip_b)] {
+        for node in nodes.iter_mut() {
+            node.entry ^= flip;
+        }
+    }
+
+    let mut visited = vec![false; crossings.len()];
+    let mut results = Vec::new();
+    for start in 0..crossings.len() {
+        // Starting from an exit would trace the boundary backwards
+        if visited[start] || !a_nodes[a_positions[start]].entry {
+            continue;
+        }
+        let mut points = Vec::new();
+        let (mut on_a, mut pos) = (true, a_positions[start]);
+        loop {
+            let list = if on_a { &a_nodes } else { &b_nodes };
+            visited[list[pos].crossing.unwrap()] = true;
+            points.push(list[pos].point.clone());
+
+            // Entering the other polygon means following this boundary
+            // forward, otherwise backwards, until the next crossing
+            let forward = list[pos].entry;
+            loop {
+                pos = match forward {
+                    true  => (pos + 1) % list.len(),
+                    false => (pos + list.len() - 1) % list.len(),
+                };
+                if list[pos].crossing.is_some() {
+                    break;
+                }
+                points.push(list[pos].point.clone());
+            }
+
+            let k = list[pos].crossing.unwrap();
+            if k == start {
+                break;
+            }
+            on_a = !on_a;
+            pos = if on_a { a_positions[k] } else { b_positions[k] };
+        }
+
+        // Crossings are rounded so a result with edges along a nearly
+        // straight stretch of boundary can fail the simplicity checks
+        // that `Polygon::new` makes
+        points.dedup();
+        if points.len() >= 3 {
+            results.push(Polygon::new_unchecked(points));
+        }
+    }
+    results
+}
+
+fn without_crossings(a: &Polygon, b: &Polygon, operation: Operation) -> Vec<Polygon> {
+    // Boundaries don't cross so they are nested or disjoint, which in
+    // general position is decided by any single vertex
+    let a_in_b = b.contains_point(a.points()[0]);
+    let b_in_a = a.contains_point(b.points()[0]);
+    match (operation, a_in_b, b_in_a) {
+        (Operation::Intersection, true, _) => vec![ccw_polygon(a)],
+        (Operation::Intersection, _, true) => vec![ccw_polygon(b)],
+        (Operation::Intersection, _, _) => Vec::new(),
+        (Operation::Union, true, _) => vec![ccw_polygon(b)],
+        (Operation::Union, _, true) => vec![ccw_polygon(a)],
+        (Operation::Union, _, _) => vec![ccw_polygon(a), ccw_polygon(b)],
+        (Operation::Difference, true, _) => Vec::new(),
+        (Operation::Difference, _, true) => vec![ccw_polygon(a), cw_polygon(b)],
+        (Operation::Difference, _, _) => vec![ccw_polygon(a)],
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+    use crate::{test_util::square, F64_ASSERT_PRECISION};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use rstest::rstest;
+    use std::f64::consts::TAU;
+
+    fn polygon(coords: &[(f64, f64)]) -> Polygon {
+        Polygon::new(coords.iter().map(|(x, y)| Point::new(*x, *y)).collect())
+    }
+
+    fn comb() -> Polygon {
+        // Three teeth pointing up from a base spanning x in [0, 5]
+        polygon(&[
+            (0.0, 0.0), (5.0, 0.0), (5.0, 3.0), (4.0, 3.0), (4.0, 1.0),
+            (3.0, 1.0), (3.0, 3.0), (2.0, 3.0), (2.0, 1.0), (1.0, 1.0),
+            (1.0, 3.0), (0.0, 3.0),
+        ])
+    }
+
+    fn signed_areas(polygons: &[Polygon]) -> f64 {
+        polygons.iter().map(|p| p.signed_area()).sum()
+    }
+
+    #[test]
+    fn test_intersection_overlapping_squares() {
+        let result = square(0.0, 0.0, 2.0).intersection(&square(1.0, 1.0, 2.0));
+        assert_eq!(result.len(), 1);
+        assert_approx_eq!(result[0].area(), 1.0, F64_ASSERT_PRECISION);
+        assert_eq!(result[0].orientation(), Orientation::CounterClockwise);
+        assert!(result[0].contains_point(&Point::new(1.5, 1.5)));
+    }
+
+    #[test]
+    fn test_intersection_splits_into_pieces() {
+        // Bar across the teeth of the comb picks out each tooth
+        let bar = polygon(&[(-0.5, 1.5), (5.5, 1.5), (5.5, 2.5), (-0.5, 2.5)]);
+        let result = comb().intersection(&bar);
+        assert_eq!(result.len(), 3);
+        for piece in result.iter() {
+            assert_approx_eq!(piece.area(), 1.0, F64_ASSERT_PRECISION);
+            assert_eq!(piece.orientation(), Orientation::CounterClockwise);
+        }
+        let reversed = bar.intersection(&comb());
+        assert_eq!(reversed.len(), 3);
+        assert_approx_eq!(signed_areas(&reversed), 3.0, F64_ASSERT_PRECISION);
+    }
+
+    #[test]
+    fn test_intersection_non_convex() {
+        // Upside down comb shifted so its teeth half overlap the teeth
+        // of the other comb, which leaves a 0.5 x 1.5 piece per tooth
+        let flipped = polygon(&[
+            (0.5, 1.5), (1.5, 1.5), (1.5, 3.5), (2.5, 3.5), (2.5, 1.5),
+            (3.5, 1.5), (3.5, 3.5), (4.5, 3.5), (4.5, 1.5), (5.5, 1.5),
+            (5.5, 4.5), (0.5, 4.5),
+        ]);
+        let result = comb().intersection(&flipped);
+        assert_eq!(result.len(), 3);
+        for piece in result.iter() {
+            assert_approx_eq!(piece.area(), 0.75, F64_ASSERT_PRECISION);
+            assert_eq!(piece.num_vertices(), 4);
+            assert!(piece.is_simple());
+        }
+    }
+
+    #[test]
+    fn test_intersection_nested() {
+        let outer = square(0.0, 0.0, 4.0);
+        let inner = square(1.0, 1.0, 2.0);
+        for result in [outer.intersection(&inner), inner.intersection(&outer)] {
+            assert_eq!(result.len(), 1);
+            assert_approx_eq!(result[0].area(), 4.0, F64_ASSERT_PRECISION);
+        }
+    }
+
+    #[test]
+    fn test_intersection_disjoint() {
+        let result = square(0.0, 0.0, 1.0).intersection(&square(2.0, 2.0, 1.0));
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_intersection_orientation_independent() {
+        let mut a = square(0.0, 0.0, 2.0);
+        a.ensure_cw();
+        let result = a.intersection(&square(1.0, 1.0, 2.0));
+        assert_eq!(result.len(), 1);
+        assert_approx_eq!(result[0].area(), 1.0, F64_ASSERT_PRECISION);
+    }
+
+    #[test]
+    fn test_union() {
+        let result = square(0.0, 0.0, 2.0).union(&square(1.0, 1.0, 2.0));
+        assert_eq!(result.len(), 1);
+        assert_approx_eq!(result[0].area(), 7.0, F64_ASSERT_PRECISION);
+        assert_eq!(result[0].num_vertices(), 8);
+
+        let disjoint = square(0.0, 0.0, 1.0).union(&square(2.0, 2.0, 1.0));
+        assert_eq!(disjoint.len(), 2);
+    }
+
+    #[test]
+    fn test_union_with_hole() {
+        // Bar across the open top of a U closes it off into a ring
+        let u = polygon(&[
+            (0.0, 0.0), (3.0, 0.0), (3.0, 3.0), (2.0, 3.0),
+            (2.0, 1.0), (1.0, 1.0), (1.0, 3.0), (0.0, 3.0),
+        ]);
+        let bar = polygon(&[(-0.5, 2.0), (3.5, 2.0), (3.5, 2.5), (-0.5, 2.5)]);
+        let result = u.union(&bar);
+        assert_eq!(result.len(), 2);
+        let holes = result.iter()
+            .filter(|p| p.orientation() == Orientation::Clockwise)
+            .collect::<Vec<_>>();
+        assert_eq!(holes.len(), 1);
+        assert_approx_eq!(holes[0].area(), 1.0, F64_ASSERT_PRECISION);
+        assert_approx_eq!(signed_areas(&result), u.area() + bar.area() - 0.5 * 2.0, F64_ASSERT_PRECISION);
+    }
+
+    #[test]
+    fn test_difference() {
+        let result = square(0.0, 0.0, 2.0).difference(&square(1.0, 1.0, 2.0));
+        assert_eq!(result.len(), 1);
+        assert_approx_eq!(result[0].area(), 3.0, F64_ASSERT_PRECISION);
+        assert!(!result[0].contains_point(&Point::new(1.5, 1.5)));
+        assert!( result[0].contains_point(&Point::new(0.5, 0.5)));
+
+        // Cutting the teeth off the comb leaves its base
+        let bar = polygon(&[(-0.5, 1.5), (5.5, 1.5), (5.5, 3.5), (-0.5, 3.5)]);
+        let result = comb().difference(&bar);
+        assert_eq!(result.len(), 1);
+        assert_approx_eq!(result[0].area(), comb().area() - 3.0 * 1.5, F64_ASSERT_PRECISION);
+    }
+
+    #[test]
+    fn test_difference_nested() {
+        let outer = square(0.0, 0.0, 4.0);
+        let inner = square(1.0, 1.0, 2.0);
+        assert!(inner.difference(&outer).is_empty());
+
+        let result = outer.difference(&inner);
+        assert_eq!(result.len(), 2);
+        assert_approx_eq!(signed_areas(&result), 12.0, F64_ASSERT_PRECISION);
+    }
+
+    fn random_star(rng: &mut StdRng, n: usize, cx: f64, cy: f64) -> Polygon {
+        // Star-shaped so it's always simple, with random radii giving
+        // plenty of reflex vertices
+        let points = (0..n)
+            .map(|i| {
+                let theta = TAU * (i as f64 + rng.gen_range(0.0..0.5)) / n as f64;
+                let r = rng.gen_range(1.0..10.0);
+                Point::new(cx + r * theta.cos(), cy + r * theta.sin())
+            })
+            .collect();
+        Polygon::new(points)
+    }
+
+    #[rstest]
+    fn test_inclusion_exclusion_random(
+        #[values(5, 12, 30)] n: usize,
+        #[values(0, 1, 2, 3, 4)] seed: u64
+    ) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let a = random_star(&mut rng, n, 0.0, 0.0);
+        let b = random_star(&mut rng, n, 3.0, 2.0);
+        let union = signed_areas(&a.union(&b));
+        let intersection = signed_areas(&a.intersection(&b));
+        let a_minus_b = signed_areas(&a.difference(&b));
+        let b_minus_a = signed_areas(&b.difference(&a));
+        assert!(intersection <= a.area().min(b.area()) + F64_ASSERT_PRECISION);
+        assert_approx_eq!(union, a.area() + b.area() - intersection, F64_ASSERT_PRECISION);
+        assert_approx_eq!(a_minus_b, a.area() - intersection, F64_ASSERT_PRECISION);
+        assert_approx_eq!(union, a_minus_b + b_minus_a + intersection, F64_ASSERT_PRECISION);
+    }
+
+    #[test]
+    fn test_inclusion_exclusion() {
+        // Area of the union is the sum minus the intersection
+        let a = comb();
+        let b = polygon(&[(0.5, -1.0), (4.5, 0.5), (2.5, 4.0)]);
+        let union = signed_areas(&a.union(&b));
+        let intersection = signed_areas(&a.intersection(&b));
+        let difference = signed_areas(&a.difference(&b));
+        assert_approx_eq!(union, a.area() + b.area() - intersection, F64_ASSERT_PRECISION);
+        assert_approx_eq!(difference, a.area() - intersection, F64_ASSERT_PRECISION);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::point::Point;
 
+#[derive(Debug, PartialEq)]
 pub struct BoundingBox {
     pub min_x: f64,
     pub max_x: f64,
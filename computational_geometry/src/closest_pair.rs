@@ -0,0 +1,138 @@
+use crate::point::Point;
+
+
+pub fn closest_pair(points: &[Point]) -> Option<(Point, Point, f64)> {
+    // Divide and conquer over the points sorted lexicographically. Each
+    // half is solved recursively and then only points within the best
+    // distance of the dividing line (the strip) need to be compared
+    // across halves. Halves are merge-sorted by y as the recursion
+    // unwinds so the strip is built in linear time, O(n log n) overall.
+    if points.len() < 2 {
+        return None;
+    }
+    let mut sorted = points.iter().collect::<Vec<_>>();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    let (p1, p2, d) = closest_pair_sorted(&mut sorted);
+    Some((p1.clone(), p2.clone(), d))
+}
+
+fn closest_pair_sorted<'a>(points: &mut [&'a Point]) -> (&'a Point, &'a Point, f64) {
+    // Expects points sorted by x on input and leaves them sorted by y
+    let n = points.len();
+    if n <= 3 {
+        let best = brute_force(points);
+        points.sort_by(|a, b| a.y.total_cmp(&b.y));
+        return best;
+    }
+
+    let mid = n / 2;
+    let mid_x = points[mid].x;
+    let (left, right) = points.split_at_mut(mid);
+    let best_left = closest_pair_sorted(left);
+    let best_right = closest_pair_sorted(right);
+    let mut best = if best_left.2 <= best_right.2 { best_left } else { best_right };
+
+    let mut merged = Vec::with_capacity(n);
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        if left[i].y <= right[j].y {
+            merged.push(left[i]);
+            i += 1;
+        } else {
+            merged.push(right[j]);
+            j += 1;
+        }
+    }
+    merged.extend_from_slice(&left[i..]);
+    merged.extend_from_slice(&right[j..]);
+    points.copy_from_slice(&merged);
+
+    // Only a constant number of strip neighbors sorted by y can be
+    // closer than the current best, so the inner loop is bounded
+    let strip = points.iter()
+        .filter(|p| (p.x - mid_x).abs() < best.2)
+        .copied()
+        .collect::<Vec<_>>();
+    for (i, p1) in strip.iter().enumerate() {
+        for p2 in strip[i + 1..].iter() {
+            if p2.y - p1.y >= best.2 {
+                break;
+            }
+            let d = p1.distance_to(p2);
+            if d < best.2 {
+                best = (p1, p2, d);
+            }
+        }
+    }
+    best
+}
+
+fn brute_force<'a>(points: &[&'a Point]) -> (&'a Point, &'a Point, f64) {
+    let mut best = (points[0], points[1], points[0].distance_to(points[1]));
+    for (i, p1) in points.iter().enumerate() {
+        for p2 in points[i + 1..].iter() {
+            let d = p1.distance_to(p2);
+            if d < best.2 {
+                best = (p1, p2, d);
+            }
+        }
+    }
+    best
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use rstest::rstest;
+
+    fn random_points(n: usize, seed: u64) -> Vec<Point> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..n)
+            .map(|_| Point::new(rng.gen_range(-100.0..100.0), rng.gen_range(-100.0..100.0)))
+            .collect()
+    }
+
+    #[test]
+    fn test_too_few_points() {
+        assert_eq!(closest_pair(&[]), None);
+        assert_eq!(closest_pair(&[Point::new(1.0, 2.0)]), None);
+    }
+
+    #[test]
+    fn test_two_points() {
+        let points = vec![Point::new(0.0, 0.0), Point::new(3.0, 4.0)];
+        let (_, _, d) = closest_pair(&points).unwrap();
+        assert_eq!(d, 5.0);
+    }
+
+    #[test]
+    fn test_duplicate_points() {
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(5.0, 5.0),
+            Point::new(2.0, 7.0),
+            Point::new(5.0, 5.0),
+            Point::new(9.0, 1.0),
+        ];
+        let (p1, p2, d) = closest_pair(&points).unwrap();
+        assert_eq!(d, 0.0);
+        assert_eq!(p1, Point::new(5.0, 5.0));
+        assert_eq!(p2, Point::new(5.0, 5.0));
+    }
+
+    #[rstest]
+    fn test_matches_brute_force(
+        #[values(2, 3, 4, 10, 100, 1000)] n: usize,
+        #[values(0, 1, 2)] seed: u64
+    ) {
+        let points = random_points(n, seed);
+        let (p1, p2, d) = closest_pair(&points).unwrap();
+        assert_eq!(p1.distance_to(&p2), d);
+
+        let refs = points.iter().collect::<Vec<_>>();
+        let (_, _, expected) = brute_force(&refs);
+        assert_eq!(d, expected);
+    }
+}
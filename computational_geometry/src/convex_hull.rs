@@ -0,0 +1,837 @@
+use crate::{
+    line_segment::LineSegment,
+    point::Point,
+    polygon::Polygon,
+};
+
+
+pub trait ConvexHullComputer {
+    // Computes the hull directly from a set of (unordered) points, so
+    // the input does not need to form a valid polygon. None if there
+    // aren't 3 non-collinear points, since the hull is then a point or
+    // a segment rather than a polygon, the same as `IncrementalHull`.
+    fn convex_hull_of_points(&self, points: &[Point]) -> Option<Polygon>;
+
+    fn convex_hull(&self, polygon: &Polygon) -> Option<Polygon> {
+        let points = polygon.vertices()
+            .iter()
+            .map(|v| v.point().clone())
+            .collect::<Vec<_>>();
+        self.convex_hull_of_points(&points)
+    }
+}
+
+
+// Decisions made while building a hull, for stepping through them in
+// the visualizer. Each step has a snapshot of the chain being built
+// after the step, rather than just a delta, so any step can be drawn
+// without replaying the ones before it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum HullStep {
+    // Candidate is next to be added, chain is unchanged
+    Consider { candidate: Point, chain: Vec<Point> },
+    // Top of the chain didn't make a strict left turn with the candidate
+    Pop { popped: Point, candidate: Point, chain: Vec<Point> },
+    Push { pushed: Point, chain: Vec<Point> },
+    // Finished one of the half hulls
+    ChainComplete { chain: Vec<Point> },
+}
+
+
+#[derive(Default)]
+pub struct MonotoneChain;
+
+impl ConvexHullComputer for MonotoneChain {
+    fn convex_hull_of_points(&self, points: &[Point]) -> Option<Polygon> {
+        self.convex_hull_of_points_traced(points, None)
+    }
+}
+
+impl MonotoneChain {
+    pub fn convex_hull_traced(&self, polygon: &Polygon) -> (Option<Polygon>, Vec<HullStep>) {
+        // Same hull as `convex_hull` along with every step taken in the
+        // lower and then upper half hulls
+        let points = polygon.vertices()
+            .iter()
+            .map(|v| v.point().clone())
+            .collect::<Vec<_>>();
+        let mut steps = Vec::new();
+        let hull = self.convex_hull_of_points_traced(&points, Some(&mut steps));
+        (hull, steps)
+    }
+
+    fn convex_hull_of_points_traced(&self, points: &[Point], trace: Option<&mut Vec<HullStep>>) -> Option<Polygon> {
+        let points = sorted_unique(points);
+        hull_polygon(self.hull_of_sorted(&points, trace))
+    }
+
+    fn hull_of_sorted<'a>(&self, points: &[&'a Point], mut trace: Option<&mut Vec<HullStep>>) -> Vec<&'a Point> {
+        // Expects lexicographically sorted unique points. Lower hull is
+        // built left to right and upper hull right to left, so joining
+        // them yields a CCW ordering. The last point of each chain is
+        // the first point of the other
+        if points.len() <= 2 {
+            return points.to_vec();
+        }
+        let mut lower = self.half_hull(points.iter().copied(), trace.as_deref_mut());
+        let mut upper = self.half_hull(points.iter().rev().copied(), trace);
+        lower.pop();
+        upper.pop();
+        let mut hull = lower;
+        hull.extend(upper);
+        hull
+    }
+
+    fn half_hull<'a, I>(&self, points: I, mut trace: Option<&mut Vec<HullStep>>) -> Vec<&'a Point>
+    where
+        I: Iterator<Item = &'a Point>,
+    {
+        let mut hull: Vec<&Point> = Vec::new();
+        let snapshot = |hull: &[&Point]| hull.iter().map(|p| (*p).clone()).collect::<Vec<_>>();
+        for p in points {
+            if let Some(steps) = trace.as_deref_mut() {
+                steps.push(HullStep::Consider { candidate: p.clone(), chain: snapshot(&hull) });
+            }
+            // Only keep strict left turns so that points collinear
+            // with a hull edge are dropped from the hull
+            while hull.len() >= 2 {
+                let top = LineSegment::new(hull[hull.len() - 2], hull[hull.len() - 1]);
+                if p.left(&top) {
+                    break;
+                }
+                let popped = hull.pop().unwrap();
+                if let Some(steps) = trace.as_deref_mut() {
+                    steps.push(HullStep::Pop {
+                        popped: popped.clone(),
+                        candidate: p.clone(),
+                        chain: snapshot(&hull),
+                    });
+                }
+            }
+            hull.push(p);
+            if let Some(steps) = trace.as_deref_mut() {
+                steps.push(HullStep::Push { pushed: p.clone(), chain: snapshot(&hull) });
+            }
+        }
+        if let Some(steps) = trace {
+            steps.push(HullStep::ChainComplete { chain: snapshot(&hull) });
+        }
+        hull
+    }
+}
+
+
+fn hull_polygon(hull: Vec<&Point>) -> Option<Polygon> {
+    // Fewer than 3 hull vertices means the points were all the same or
+    // collinear, which can't be validated as a polygon
+    (hull.len() >= 3).then(|| Polygon::new(hull.into_iter().cloned().collect()))
+}
+
+
+fn sorted_unique(points: &[Point]) -> Vec<&Point> {
+    let mut points = points.iter().collect::<Vec<_>>();
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    points.dedup();
+    points
+}
+
+
+#[derive(Default)]
+pub struct ChanHull;
+
+impl ConvexHullComputer for ChanHull {
+    fn convex_hull_of_points(&self, points: &[Point]) -> Option<Polygon> {
+        // Guesses the hull size m with squaring, each guess costing
+        // O(n log m), so the total is O(n log h) for h hull vertices
+        let points = sorted_unique(points);
+        if points.len() < 3 {
+            return None;
+        }
+        let mut m: usize = 4;
+        loop {
+            if let Some(hull) = self.partial_hull(&points, m.min(points.len())) {
+                // All collinear gives None the same as monotone chain
+                return hull_polygon(hull);
+            }
+            m = m.saturating_mul(m);
+        }
+    }
+}
+
+impl ChanHull {
+    fn partial_hull<'a>(&self, points: &[&'a Point], m: usize) -> Option<Vec<&'a Point>> {
+        // Hulls groups of m points with monotone chain, then gift wraps
+        // around those hulls for at most m steps using binary searched
+        // tangents. Returns None if the hull has more than m vertices.
+        let groups = points.chunks(m)
+            .map(|group| MonotoneChain.hull_of_sorted(group, None))
+            .collect::<Vec<_>>();
+
+        // Lexicographically smallest point is always on the hull
+        let start = points[0];
+        let mut hull = vec![start];
+        let mut current = start;
+        for _ in 0..m {
+            let mut best: Option<&Point> = None;
+            for group in groups.iter() {
+                if let Some(q) = self.tangent(group, current) {
+                    if best.map_or(true, |b| wraps_past(current, b, q)) {
+                        best = Some(q);
+                    }
+                }
+            }
+            let next = best.unwrap();
+            if next == start {
+                return Some(hull);
+            }
+            hull.push(next);
+            current = next;
+        }
+        None
+    }
+
+    fn tangent<'a>(&self, hull: &[&'a Point], p: &Point) -> Option<&'a Point> {
+        // Finds the hull vertex q where the whole (CCW, strictly convex)
+        // hull is left of or on p -> q, assuming p is not strictly inside
+        let k = hull.len();
+        if let Some(i) = hull.iter().position(|q| *q == p) {
+            return match k {
+                1 => None,
+                _ => Some(hull[(i + 1) % k]),
+            };
+        }
+        if k <= 2 {
+            return hull.iter().copied().reduce(|b, q| if wraps_past(p, b, q) { q } else { b });
+        }
+
+        let is_tangent = |i: usize| {
+            let e = LineSegment::new(p, hull[i]);
+            hull[(i + k - 1) % k].left_on(&e) && hull[(i + 1) % k].left_on(&e)
+        };
+        if let Some(i) = self.tangent_search(hull, p) {
+            if is_tangent(i) {
+                return Some(hull[i]);
+            }
+        }
+        // Binary search can be thrown off by degenerate configurations
+        hull.iter().copied().reduce(|b, q| if wraps_past(p, b, q) { q } else { b })
+    }
+
+    fn tangent_search(&self, hull: &[&Point], p: &Point) -> Option<usize> {
+        // Binary search for the tangent following Dan Sunday's approach,
+        // where "above" and "below" are relative to the ray from p
+        let k = hull.len();
+        let v = |i: usize| hull[i % k];
+        let side = |a: &Point, b: &Point| (a - p).cross(&(b - p));
+        let above = |i: usize, j: usize| side(v(i), v(j)) > 0.0;
+        let below = |i: usize, j: usize| side(v(i), v(j)) < 0.0;
+
+        if below(1, 0) && !above(k - 1, 0) {
+            return Some(0);
+        }
+        let (mut a, mut b) = (0, k);
+        while b - a > 1 {
+            let c = (a + b) / 2;
+            let down_c = below(c + 1, c);
+            if down_c && !above(c - 1, c) {
+                return Some(c);
+            }
+            let up_a = above(a + 1, a);
+            if up_a {
+                if down_c || above(a, c) {
+                    b = c;
+                } else {
+                    a = c;
+                }
+            } else if !down_c || !below(a, c) {
+                a = c;
+            } else {
+                b = c;
+            }
+        }
+        None
+    }
+}
+
+fn wraps_past(p: &Point, best: &Point, q: &Point) -> bool {
+    // True if q is a better next gift wrapping point than the current
+    // best, i.e. q is right of p -> best, or collinear and farther away
+    let e = LineSegment::new(p, best);
+    if !q.left_on(&e) {
+        return true;
+    }
+    !q.left(&e) && LineSegment::new(p, q).length_squared() > e.length_squared()
+}
+
+
+pub struct IncrementalHull {
+    // CCW hull vertices once there are 3 non-collinear points, until
+    // then all (unique) points seen so far
+    points: Vec<Point>,
+    degenerate: bool,
+}
+
+impl Default for IncrementalHull {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IncrementalHull {
+    pub fn new() -> Self {
+        IncrementalHull { points: Vec::new(), degenerate: true }
+    }
+
+    pub fn insert(&mut self, p: Point) {
+        if self.degenerate {
+            if !self.points.contains(&p) {
+                self.points.push(p);
+            }
+            let sorted = sorted_unique(&self.points);
+            let hull = MonotoneChain.hull_of_sorted(&sorted, None);
+            if hull.len() >= 3 {
+                self.points = hull.into_iter().cloned().collect();
+                self.degenerate = false;
+            }
+            return;
+        }
+
+        // Edges visible from p are replaced by the two tangent edges to
+        // p. Points collinear with an edge but beyond it also see that
+        // edge, so that the hull never keeps a collinear vertex.
+        let n = self.points.len();
+        let visible = (0..n)
+            .map(|i| {
+                let (a, b) = (&self.points[i], &self.points[(i + 1) % n]);
+                let e = LineSegment::new(a, b);
+                if !p.left_on(&e) {
+                    return true;
+                }
+                let within = p.x >= a.x.min(b.x) && p.x <= a.x.max(b.x)
+                    && p.y >= a.y.min(b.y) && p.y <= a.y.max(b.y);
+                !p.left(&e) && !within
+            })
+            .collect::<Vec<_>>();
+        // Visible edges are contiguous, find where the run starts
+        let Some(start) = (0..n).find(|&i| visible[i] && !visible[(i + n - 1) % n]) else {
+            // Inside or on the boundary so the hull is unchanged
+            return;
+        };
+        let mut end = start;
+        while visible[(end + 1) % n] {
+            end = (end + 1) % n;
+        }
+
+        // Vertices strictly between the first and last visible edge are
+        // no longer on the hull, p goes between the remaining endpoints
+        let mut hull = vec![p];
+        let mut i = (end + 1) % n;
+        loop {
+            hull.push(self.points[i].clone());
+            if i == start {
+                break;
+            }
+            i = (i + 1) % n;
+        }
+        self.points = hull;
+    }
+
+    pub fn hull(&self) -> Option<Polygon> {
+        // Starts from the lexicographically smallest vertex so the
+        // result matches the batch algorithms here vertex for vertex.
+        // None until there are 3 non-collinear points.
+        if self.degenerate {
+            return None;
+        }
+        let start = self.points.iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)))
+            .map(|(i, _)| i)
+            .unwrap();
+        let mut points = self.points.clone();
+        points.rotate_left(start);
+        Some(Polygon::new(points))
+    }
+}
+
+
+pub struct AklToussaint {
+    inner: Box<dyn ConvexHullComputer>,
+}
+
+impl AklToussaint {
+    pub fn new(inner: Box<dyn ConvexHullComputer>) -> Self {
+        AklToussaint { inner }
+    }
+
+    pub fn filter_points(&self, points: &[Point]) -> Vec<Point> {
+        // Discards points strictly inside the quadrilateral formed by
+        // the extreme points in x and y, since those can't be on the
+        // hull. Points on the quadrilateral boundary are kept.
+        if points.is_empty() {
+            return Vec::new();
+        }
+        let extreme = |cmp: fn(&Point, &Point) -> std::cmp::Ordering| {
+            points.iter().min_by(|a, b| cmp(a, b)).unwrap()
+        };
+        let mut quad = vec![
+            extreme(|a, b| a.x.total_cmp(&b.x)),
+            extreme(|a, b| a.y.total_cmp(&b.y)),
+            extreme(|a, b| b.x.total_cmp(&a.x)),
+            extreme(|a, b| b.y.total_cmp(&a.y)),
+        ];
+        quad.dedup();
+        if quad.len() > 1 && quad[0] == quad[quad.len() - 1] {
+            quad.pop();
+        }
+        if quad.len() < 3 {
+            return points.to_vec();
+        }
+
+        let edges = (0..quad.len())
+            .map(|i| LineSegment::new(quad[i], quad[(i + 1) % quad.len()]))
+            .collect::<Vec<_>>();
+        points.iter()
+            .filter(|p| !edges.iter().all(|e| p.left(e)))
+            .cloned()
+            .collect()
+    }
+}
+
+impl ConvexHullComputer for AklToussaint {
+    fn convex_hull_of_points(&self, points: &[Point]) -> Option<Polygon> {
+        self.inner.convex_hull_of_points(&self.filter_points(points))
+    }
+}
+
+
+impl Polygon {
+    pub fn convex_hull(&self) -> Option<Polygon> {
+        // Monotone chain is the default, it's O(n log n), simple and
+        // doesn't need any tuning. Use `convex_hull_with` to pick another.
+        // None if the vertices are all collinear.
+        self.convex_hull_with(&MonotoneChain)
+    }
+
+    pub fn convex_hull_with(&self, computer: &(impl ConvexHullComputer + ?Sized)) -> Option<Polygon> {
+        computer.convex_hull(self)
+    }
+
+    pub fn interior_points(&self) -> Vec<Point> {
+        // Vertices strictly inside the convex hull, in boundary order.
+        // Vertices collinear with a hull edge are on the hull so aren't
+        // interior. Same as those strictly inside a triangle of three
+        // other vertices, but in O(n log n) for the hull and O(nh) for
+        // the boundary checks instead of O(n^4) by checking every
+        // triangle. Nothing is interior if the vertices are collinear.
+        let Some(hull) = self.convex_hull() else {
+            return Vec::new();
+        };
+        self.to_points()
+            .into_iter()
+            .filter(|p| !hull.is_point_on_boundary(p))
+            .collect()
+    }
+
+    pub fn convexity(&self) -> Option<f64> {
+        // Fraction of the convex hull covered by the polygon, 1 exactly
+        // for convex polygons up to rounding and smaller the deeper its
+        // concavities are. None if the vertices are collinear so there's
+        // no hull area to compare against.
+        self.convex_hull().map(|hull| self.area() / hull.area())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+    use crate::{triangle::Triangle, F64_ASSERT_PRECISION};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::monotone_chain(Box::new(MonotoneChain))]
+    #[case::chan(Box::new(ChanHull))]
+    #[case::akl_toussaint(Box::new(AklToussaint::new(Box::new(MonotoneChain))))]
+    fn test_collinear_boundary_vertex_excluded(#[case] computer: Box<dyn ConvexHullComputer>) {
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(0.0, 4.0),
+        ];
+        let polygon = Polygon::new(points);
+        let hull = computer.convex_hull(&polygon).unwrap();
+        let expected = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(0.0, 4.0),
+        ]);
+        assert_eq!(hull, expected);
+
+        // Middle point is on the hull boundary rather than interior to
+        // it, whichever way the hull is triangulated
+        let middle = Point::new(2.0, 0.0);
+        assert!(hull.is_point_on_boundary(&middle));
+        let hull_points = hull.to_points();
+        for (a, b, c) in (0..4).map(|i| (i, (i + 1) % 4, (i + 2) % 4)) {
+            let triangle = Triangle::new(&hull_points[a], &hull_points[b], &hull_points[c]);
+            assert!(!triangle.contains_strict(&middle));
+        }
+    }
+
+    #[test]
+    fn test_incremental_collinear_boundary_vertex_excluded() {
+        let mut hull = IncrementalHull::new();
+        for p in [(0.0, 0.0), (2.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)] {
+            hull.insert(Point::new(p.0, p.1));
+        }
+        let middle = Point::new(2.0, 0.0);
+        let hull = hull.hull().unwrap();
+        assert_eq!(hull.num_vertices(), 4);
+        assert!(!hull.to_points().contains(&middle));
+        assert!(hull.is_point_on_boundary(&middle));
+    }
+
+    #[test]
+    fn test_traced_steps() {
+        // Square with one interior point, which is pushed on the lower
+        // chain and later popped
+        let polygon = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(2.0, 1.0),
+            Point::new(0.0, 4.0),
+        ]);
+        let (hull, steps) = MonotoneChain.convex_hull_traced(&polygon);
+        assert_eq!(hull, MonotoneChain.convex_hull(&polygon));
+
+        // Lower chain visits (0, 4) before the interior point since
+        // points are sorted by x, both are popped as the chain turns right
+        let interior = Point::new(2.0, 1.0);
+        assert!(steps.contains(&HullStep::Pop {
+            popped: Point::new(0.0, 4.0),
+            candidate: interior.clone(),
+            chain: vec![Point::new(0.0, 0.0)],
+        }));
+        assert!(steps.contains(&HullStep::Push {
+            pushed: interior.clone(),
+            chain: vec![Point::new(0.0, 0.0), interior.clone()],
+        }));
+        assert!(steps.contains(&HullStep::Pop {
+            popped: interior.clone(),
+            candidate: Point::new(4.0, 0.0),
+            chain: vec![Point::new(0.0, 0.0)],
+        }));
+        let chains = steps.iter()
+            .filter_map(|s| match s {
+                HullStep::ChainComplete { chain } => Some(chain.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(chains, vec![
+            vec![Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(4.0, 4.0)],
+            vec![Point::new(4.0, 4.0), Point::new(0.0, 4.0), Point::new(0.0, 0.0)],
+        ]);
+    }
+
+    #[rstest]
+    fn test_traced_matches_untraced(#[values(0, 1, 2)] seed: u64) {
+        let polygon = Polygon::random_simple(30, seed);
+        let (hull, steps) = MonotoneChain.convex_hull_traced(&polygon);
+        assert_eq!(hull, MonotoneChain.convex_hull(&polygon));
+
+        // Every point is considered and pushed once per half hull, and
+        // each push or pop changes the chain by exactly that point
+        let count = |f: fn(&HullStep) -> bool| steps.iter().filter(|s| f(s)).count();
+        assert_eq!(count(|s| matches!(s, HullStep::Consider { .. })), 60);
+        assert_eq!(count(|s| matches!(s, HullStep::Push { .. })), 60);
+        assert_eq!(count(|s| matches!(s, HullStep::ChainComplete { .. })), 2);
+        for (prev, step) in steps.iter().zip(steps.iter().skip(1)) {
+            let prev_chain = match prev {
+                HullStep::Consider { chain, .. }
+                | HullStep::Pop { chain, .. }
+                | HullStep::Push { chain, .. } => chain,
+                HullStep::ChainComplete { .. } => continue,
+            };
+            match step {
+                HullStep::Pop { popped, chain, .. } => {
+                    assert_eq!(chain.len() + 1, prev_chain.len());
+                    assert_eq!(prev_chain.last(), Some(popped));
+                }
+                HullStep::Push { pushed, chain } => {
+                    assert_eq!(chain.len(), prev_chain.len() + 1);
+                    assert_eq!(chain.last(), Some(pushed));
+                }
+                HullStep::Consider { chain, .. } | HullStep::ChainComplete { chain } => {
+                    assert_eq!(chain, prev_chain);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_all_collinear() {
+        let points = vec![
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 1.0),
+        ];
+        let polygon = Polygon::new(points);
+        assert_eq!(MonotoneChain.convex_hull(&polygon), None);
+        assert_eq!(ChanHull.convex_hull(&polygon), None);
+        assert_eq!(polygon.convex_hull(), None);
+        assert_eq!(polygon.interior_points(), Vec::new());
+        assert_eq!(polygon.convexity(), None);
+    }
+
+    #[test]
+    fn test_many_collinear_points() {
+        // Validating the collinear points as a polygon used to fail
+        // depending on hash map iteration order, so this is repeated
+        let points = vec![
+            Point::new(3.0, 1.5),
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 2.0),
+            Point::new(1.0, 0.5),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 0.5),
+        ];
+        for _ in 0..50 {
+            assert_eq!(MonotoneChain.convex_hull_of_points(&points), None);
+            assert_eq!(ChanHull.convex_hull_of_points(&points), None);
+        }
+    }
+
+    #[test]
+    fn test_convex_hull_of_scattered_points() {
+        // Unordered points with interior and duplicate points that
+        // could not be used to construct a valid polygon directly
+        let points = vec![
+            Point::new(2.0, 2.0),
+            Point::new(4.0, 4.0),
+            Point::new(1.0, 3.0),
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(3.0, 1.0),
+            Point::new(0.0, 4.0),
+            Point::new(4.0, 4.0),
+            Point::new(2.0, 0.0),
+        ];
+        let hull = MonotoneChain.convex_hull_of_points(&points).unwrap();
+        let expected = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(0.0, 4.0),
+        ]);
+        assert_eq!(hull, expected);
+    }
+
+    #[test]
+    fn test_incremental_hull_many_collinear_points() {
+        let mut incremental = IncrementalHull::new();
+        for _ in 0..50 {
+            for i in 0..5 {
+                incremental.insert(Point::new(i as f64, 0.5 * i as f64));
+                assert_eq!(incremental.hull(), None);
+            }
+        }
+        incremental.insert(Point::new(0.0, 1.0));
+        let expected = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 2.0),
+            Point::new(0.0, 1.0),
+        ]);
+        assert_eq!(incremental.hull().unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case::monotone_chain(Box::new(MonotoneChain))]
+    #[case::chan(Box::new(ChanHull))]
+    #[case::akl_toussaint(Box::new(AklToussaint::new(Box::new(MonotoneChain))))]
+    fn test_convex_hull_of_too_few_points(#[case] computer: Box<dyn ConvexHullComputer>) {
+        assert_eq!(computer.convex_hull_of_points(&[]), None);
+        let one = vec![
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 1.0),
+        ];
+        assert_eq!(computer.convex_hull_of_points(&one), None);
+        let two = vec![
+            Point::new(2.0, 2.0),
+            Point::new(1.0, 1.0),
+            Point::new(2.0, 2.0),
+        ];
+        assert_eq!(computer.convex_hull_of_points(&two), None);
+    }
+
+    #[test]
+    fn test_akl_toussaint_filters_points() {
+        // Points uniform in a disk, where the extreme point quadrilateral
+        // covers most of the area so most points get filtered out
+        let mut rng = StdRng::seed_from_u64(0);
+        let points = (0..1000)
+            .map(|_| {
+                let r = 100.0 * rng.gen_range(0.0f64..1.0).sqrt();
+                let theta = rng.gen_range(0.0..std::f64::consts::TAU);
+                Point::new(r * theta.cos(), r * theta.sin())
+            })
+            .collect::<Vec<_>>();
+        let akl_toussaint = AklToussaint::new(Box::new(MonotoneChain));
+        let filtered = akl_toussaint.filter_points(&points);
+        assert!(filtered.len() < points.len() / 2);
+        assert_eq!(
+            akl_toussaint.convex_hull_of_points(&points),
+            MonotoneChain.convex_hull_of_points(&points)
+        );
+    }
+
+    #[test]
+    fn test_akl_toussaint_degenerate_extremes() {
+        // Collinear points only give two distinct extreme points, so
+        // there is no quadrilateral to filter with
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(2.0, 2.0),
+        ];
+        let akl_toussaint = AklToussaint::new(Box::new(MonotoneChain));
+        assert_eq!(akl_toussaint.filter_points(&points), points);
+    }
+
+    #[rstest]
+    fn test_chan_hull_matches_monotone_chain(
+        #[values(3, 10, 100, 1000)] n: usize,
+        #[values(0, 1, 2)] seed: u64
+    ) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let points = (0..n)
+            .map(|_| Point::new(rng.gen_range(-100.0..100.0), rng.gen_range(-100.0..100.0)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ChanHull.convex_hull_of_points(&points),
+            MonotoneChain.convex_hull_of_points(&points)
+        );
+    }
+
+    #[test]
+    fn test_chan_hull_collinear() {
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(2.0, 4.0),
+            Point::new(0.0, 4.0),
+            Point::new(2.0, 2.0),
+        ];
+        assert_eq!(
+            ChanHull.convex_hull_of_points(&points),
+            MonotoneChain.convex_hull_of_points(&points)
+        );
+        let collinear = vec![
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 1.0),
+        ];
+        assert_eq!(
+            ChanHull.convex_hull_of_points(&collinear),
+            MonotoneChain.convex_hull_of_points(&collinear)
+        );
+    }
+
+    #[rstest]
+    fn test_incremental_hull_matches_monotone_chain(
+        #[values(3, 10, 100, 1000)] n: usize,
+        #[values(0, 1, 2)] seed: u64
+    ) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let points = (0..n)
+            .map(|_| Point::new(rng.gen_range(-100.0..100.0), rng.gen_range(-100.0..100.0)))
+            .collect::<Vec<_>>();
+        let mut incremental = IncrementalHull::new();
+        for p in points.iter() {
+            incremental.insert(p.clone());
+        }
+        assert_eq!(incremental.hull(), MonotoneChain.convex_hull_of_points(&points));
+    }
+
+    #[test]
+    fn test_incremental_hull_collinear_and_interior_points() {
+        // Starts collinear, then includes duplicates, interior points, 
+        // points on an edge, and points extending an edge
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 0.0),
+            Point::new(0.0, 2.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(0.0, 4.0),
+        ];
+        let mut incremental = IncrementalHull::new();
+        for (i, p) in points.iter().enumerate() {
+            incremental.insert(p.clone());
+            match i >= 4 {
+                true  => assert_eq!(incremental.hull(), MonotoneChain.convex_hull_of_points(&points[..=i])),
+                false => assert_eq!(incremental.hull(), None),
+            }
+        }
+    }
+
+    #[test]
+    fn test_polygon_convex_hull() {
+        // L shape whose reflex corner is left out of the hull
+        let polygon = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 2.0),
+            Point::new(2.0, 2.0),
+            Point::new(2.0, 4.0),
+            Point::new(0.0, 4.0),
+        ]);
+        let hull = polygon.convex_hull().unwrap();
+        assert_eq!(Some(&hull), MonotoneChain.convex_hull(&polygon).as_ref());
+        assert_eq!(hull.num_vertices(), 5);
+        assert!(!hull.to_points().contains(&Point::new(2.0, 2.0)));
+
+        let computers: Vec<Box<dyn ConvexHullComputer>> = vec![
+            Box::new(ChanHull),
+            Box::new(AklToussaint::new(Box::new(MonotoneChain))),
+        ];
+        for computer in computers.iter() {
+            assert_eq!(polygon.convex_hull_with(computer.as_ref()).as_ref(), Some(&hull));
+        }
+        assert_eq!(polygon.convex_hull_with(&ChanHull).as_ref(), Some(&hull));
+    }
+
+    #[test]
+    fn test_convexity() {
+        let square = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(0.0, 4.0),
+        ]);
+        assert_approx_eq!(square.convexity().unwrap(), 1.0, F64_ASSERT_PRECISION);
+        // L shape covers 12 of the 14 units of its hull
+        let l_shape = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 2.0),
+            Point::new(2.0, 2.0),
+            Point::new(2.0, 4.0),
+            Point::new(0.0, 4.0),
+        ]);
+        assert_approx_eq!(l_shape.convexity().unwrap(), 12.0 / 14.0, F64_ASSERT_PRECISION);
+    }
+}
@@ -0,0 +1,248 @@
+use std::collections::HashMap;
+
+use crate::{
+    point::Point,
+    polygon::{Orientation, Polygon, PolygonError},
+    vertex::VertexId,
+};
+
+// Doubly-connected edge list of a polygon's subdivision of the plane.
+// Every edge is a pair of twin half-edges, one on each face it borders,
+// and each face is the cycle of half-edges following `next` from any
+// one of them. Bounded faces are CCW and the unbounded outer face is CW.
+// Built from a polygon it has the polygon's interior as its only
+// bounded face, adding diagonals (e.g. from a triangulation) splits the
+// face they cross into two.
+//
+// Half-edges and faces are referred to by their index, vertices by the
+// ID they had in the polygon.
+
+
+pub const OUTER_FACE: usize = 0;
+
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HalfEdge {
+    pub origin: VertexId,
+    pub twin: usize,
+    pub next: usize,
+    pub prev: usize,
+    pub face: usize,
+}
+
+
+pub struct Dcel {
+    points: HashMap<VertexId, Point>,
+    half_edges: Vec<HalfEdge>,
+    // One half-edge on each face, starting with the outer face
+    faces: Vec<usize>,
+}
+
+impl Dcel {
+    pub fn new(polygon: &Polygon) -> Self {
+        // Inner half-edge i goes from vertex i to i + 1 of the CCW
+        // boundary, its twin n + i back the other way on the outer face
+        let mut vertices = polygon.boundary_vertices();
+        if polygon.orientation() == Orientation::Clockwise {
+            vertices.reverse();
+        }
+        let n = vertices.len();
+        let inner = (0..n).map(|i| HalfEdge {
+            origin: vertices[i].id,
+            twin: n + i,
+            next: (i + 1) % n,
+            prev: (i + n - 1) % n,
+            face: 1,
+        });
+        let outer = (0..n).map(|i| HalfEdge {
+            origin: vertices[(i + 1) % n].id,
+            twin: i,
+            next: n + (i + n - 1) % n,
+            prev: n + (i + 1) % n,
+            face: OUTER_FACE,
+        });
+        let half_edges = inner.chain(outer).collect();
+        let points = vertices.iter()
+            .map(|v| (v.id, v.point().clone()))
+            .collect();
+        Dcel { points, half_edges, faces: vec![n, 0] }
+    }
+
+    pub fn point(&self, id: &VertexId) -> Option<&Point> {
+        self.points.get(id)
+    }
+
+    pub fn half_edges(&self) -> &[HalfEdge] {
+        &self.half_edges
+    }
+
+    pub fn num_faces(&self) -> usize {
+        // Bounded faces only, same as `faces`
+        self.faces.len() - 1
+    }
+
+    pub fn faces(&self) -> Vec<Vec<VertexId>> {
+        // Vertices of each bounded face in CCW order, the index of a
+        // face in the result is one less than its face index
+        (1..self.faces.len())
+            .map(|face| self.face_half_edges(face)
+                .into_iter()
+                .map(|e| self.half_edges[e].origin)
+                .collect())
+            .collect()
+    }
+
+    pub fn face_half_edges(&self, face: usize) -> Vec<usize> {
+        let start = self.faces[face];
+        let mut edges = vec![start];
+        let mut current = self.half_edges[start].next;
+        while current != start {
+            edges.push(current);
+            current = self.half_edges[current].next;
+        }
+        edges
+    }
+
+    pub fn add_diagonal(&mut self, a: VertexId, b: VertexId) -> Result<usize, PolygonError> {
+        // Splits the bounded face both vertices are on, returning the
+        // index of the new face which is the one on the b to a side. The
+        // caller is responsible for the segment being a diagonal of the
+        // face. Finding the face is O(E) since vertices don't keep their
+        // outgoing half-edges.
+        for id in [a, b] {
+            if !self.points.contains_key(&id) {
+                return Err(PolygonError::VertexNotFound(id));
+            }
+        }
+        let outgoing = |id: VertexId| (0..self.half_edges.len())
+            .filter(|&e| self.half_edges[e].origin == id && self.half_edges[e].face != OUTER_FACE)
+            .collect::<Vec<_>>();
+        let from_b = outgoing(b);
+        let found = outgoing(a)
+            .into_iter()
+            .flat_map(|ea| from_b.iter().map(move |&eb| (ea, eb)))
+            .find(|&(ea, eb)| self.half_edges[ea].face == self.half_edges[eb].face);
+        let Some((ea, eb)) = found.filter(|_| a != b) else {
+            return Err(PolygonError::NotADiagonal(a, b));
+        };
+        let (ea_prev, eb_prev) = (self.half_edges[ea].prev, self.half_edges[eb].prev);
+        if ea_prev == eb || eb_prev == ea {
+            return Err(PolygonError::NotADiagonal(a, b));
+        }
+
+        // New half-edges a to b on the existing face and b to a on the
+        // new one
+        let face = self.half_edges[ea].face;
+        let new_face = self.faces.len();
+        let (ab, ba) = (self.half_edges.len(), self.half_edges.len() + 1);
+        self.half_edges.push(HalfEdge { origin: a, twin: ba, next: eb, prev: ea_prev, face });
+        self.half_edges.push(HalfEdge { origin: b, twin: ab, next: ea, prev: eb_prev, face: new_face });
+        self.half_edges[ea_prev].next = ab;
+        self.half_edges[eb].prev = ab;
+        self.half_edges[eb_prev].next = ba;
+        self.half_edges[ea].prev = ba;
+        self.faces[face] = ab;
+        self.faces.push(ba);
+        for e in self.face_half_edges(new_face) {
+            self.half_edges[e].face = new_face;
+        }
+        Ok(new_face)
+    }
+}
+
+
+impl Polygon {
+    pub fn to_dcel(&self) -> Dcel {
+        Dcel::new(self)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn assert_consistent(dcel: &Dcel) {
+        let half_edges = dcel.half_edges();
+        for (i, e) in half_edges.iter().enumerate() {
+            assert_eq!(half_edges[e.twin].twin, i);
+            assert_eq!(half_edges[e.next].prev, i);
+            assert_eq!(half_edges[e.prev].next, i);
+            assert_eq!(half_edges[e.next].face, e.face);
+            // Twin starts where this one ends
+            assert_eq!(half_edges[e.twin].origin, half_edges[e.next].origin);
+        }
+    }
+
+    #[test]
+    fn test_triangulated_square() {
+        let polygon = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(0.0, 4.0),
+        ]);
+        let mut dcel = polygon.to_dcel();
+        assert_eq!(dcel.num_faces(), 1);
+        assert_eq!(dcel.faces()[0].len(), 4);
+        assert_eq!(dcel.face_half_edges(OUTER_FACE).len(), 4);
+
+        let triangulation = polygon.triangulation().unwrap();
+        for (a, b) in triangulation.diagonals() {
+            dcel.add_diagonal(a, b).unwrap();
+        }
+        assert_consistent(&dcel);
+        let faces = dcel.faces();
+        assert_eq!(faces.len(), 2);
+        for face in faces.iter() {
+            assert_eq!(face.len(), 3);
+            let points = face.iter()
+                .map(|id| dcel.point(id).unwrap().clone())
+                .collect::<Vec<_>>();
+            assert_eq!(Polygon::new(points).orientation(), Orientation::CounterClockwise);
+        }
+        // Diagonal is shared by the two faces, the outer face is unchanged
+        assert_eq!(dcel.half_edges().len(), 10);
+        assert_eq!(dcel.face_half_edges(OUTER_FACE).len(), 4);
+    }
+
+    #[test]
+    fn test_add_diagonal_errors() {
+        let polygon = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(0.0, 4.0),
+        ]);
+        let id = VertexId::from;
+        let mut dcel = polygon.to_dcel();
+        assert!(matches!(dcel.add_diagonal(id(0usize), id(1usize)), Err(PolygonError::NotADiagonal(..))));
+        assert!(matches!(dcel.add_diagonal(id(2usize), id(2usize)), Err(PolygonError::NotADiagonal(..))));
+        assert!(matches!(dcel.add_diagonal(id(0usize), id(7usize)), Err(PolygonError::VertexNotFound(_))));
+        dcel.add_diagonal(id(0usize), id(2usize)).unwrap();
+        // Already an edge of both faces
+        assert!(matches!(dcel.add_diagonal(id(2usize), id(0usize)), Err(PolygonError::NotADiagonal(..))));
+        // Vertices 1 and 3 no longer share a face
+        assert!(matches!(dcel.add_diagonal(id(1usize), id(3usize)), Err(PolygonError::NotADiagonal(..))));
+    }
+
+    #[rstest]
+    fn test_triangulated_random_polygon(#[values(0, 1, 2)] seed: u64, #[values(false, true)] cw: bool) {
+        // Triangulation needs CCW, reversing keeps the vertex IDs so the
+        // diagonals still apply
+        let mut polygon = Polygon::random_simple(20, seed);
+        polygon.ensure_ccw();
+        let diagonals = polygon.triangulation().unwrap().diagonals();
+        if cw {
+            polygon.reverse();
+        }
+        let mut dcel = polygon.to_dcel();
+        for (a, b) in diagonals {
+            dcel.add_diagonal(a, b).unwrap();
+            assert_consistent(&dcel);
+        }
+        assert_eq!(dcel.num_faces(), 18);
+        assert!(dcel.faces().iter().all(|face| face.len() == 3));
+    }
+}
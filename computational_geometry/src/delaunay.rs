@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+
+use crate::{
+    bounding_box::BoundingBox,
+    point::Point,
+    polygon::{TriangleVertexIds, Triangulation},
+    triangle::Triangle,
+    vertex_map::VertexMap,
+};
+
+
+pub struct DelaunayTriangulation {
+    vertex_map: VertexMap,
+}
+
+impl DelaunayTriangulation {
+    pub fn new(points: Vec<Point>) -> Self {
+        // Note the vertex map chains the points together in the order
+        // given, but that chain is not used for the triangulation
+        DelaunayTriangulation { vertex_map: VertexMap::new(points) }
+    }
+
+    pub fn triangulation(&self) -> Triangulation<'_> {
+        // Bowyer-Watson incremental insertion. Each point is inserted by
+        // removing every triangle whose circumcircle contains the point
+        // and re-triangulating the resulting cavity by connecting its
+        // boundary edges to the new point. Triangles are stored as CCW
+        // index triples into the point list.
+        let vertices = self.vertex_map.sorted_vertices();
+        let mut points = vertices.iter()
+            .map(|v| v.point().clone())
+            .collect::<Vec<_>>();
+        let num_points = points.len();
+        if num_points < 3 {
+            return Triangulation::new(&self.vertex_map);
+        }
+
+        // Super-triangle that encloses all points, its vertices are 
+        // removed once all points have been inserted. It is made much
+        // larger than the points so that its circumcircles approximate
+        // half-planes, otherwise triangles on the hull can be lost
+        let vmap = &self.vertex_map;
+        let bb = BoundingBox::new(vmap.min_x(), vmap.max_x(), vmap.min_y(), vmap.max_y());
+        let center = bb.center();
+        let delta = (bb.max_x - bb.min_x).max(bb.max_y - bb.min_y).max(1.0);
+        points.push(Point::new(center.x - 1000.0 * delta, center.y - 1000.0 * delta));
+        points.push(Point::new(center.x + 1000.0 * delta, center.y - 1000.0 * delta));
+        points.push(Point::new(center.x, center.y + 1000.0 * delta));
+        let mut triangles = vec![[num_points, num_points + 1, num_points + 2]];
+
+        for i in 0..num_points {
+            let p = &points[i];
+            if points[..i].contains(p) {
+                // Duplicate points would create degenerate triangles
+                continue;
+            }
+
+            let (bad, good): (Vec<_>, Vec<_>) = triangles
+                .into_iter()
+                .partition(|[a, b, c]| {
+                    Triangle::new(&points[*a], &points[*b], &points[*c]).in_circle(p)
+                });
+
+            // Cavity boundary consists of the edges that belong to 
+            // exactly one of the removed triangles
+            let mut edge_counts = HashMap::new();
+            for [a, b, c] in bad.iter() {
+                for (e1, e2) in [(*a, *b), (*b, *c), (*c, *a)] {
+                    let key = (e1.min(e2), e1.max(e2));
+                    edge_counts.entry(key).or_insert((e1, e2, 0)).2 += 1;
+                }
+            }
+
+            triangles = good;
+            for (e1, e2, count) in edge_counts.into_values() {
+                if count == 1 {
+                    triangles.push([e1, e2, i]);
+                }
+            }
+        }
+
+        let mut triangulation = Triangulation::new(&self.vertex_map);
+        for [a, b, c] in triangles {
+            if a < num_points && b < num_points && c < num_points {
+                triangulation.insert(TriangleVertexIds(
+                    vertices[a].id, vertices[b].id, vertices[c].id
+                ));
+            }
+        }
+        triangulation
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convex_hull::{ConvexHullComputer, MonotoneChain};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use rstest::rstest;
+
+    fn random_points(n: usize, seed: u64) -> Vec<Point> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..n)
+            .map(|_| Point::new(rng.gen_range(-100.0..100.0), rng.gen_range(-100.0..100.0)))
+            .collect()
+    }
+
+    #[test]
+    fn test_square() {
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(0.0, 5.0),
+        ];
+        let delaunay = DelaunayTriangulation::new(points);
+        assert_eq!(delaunay.triangulation().len(), 2);
+    }
+
+    #[rstest]
+    fn test_empty_circumcircle(
+        #[values(3, 10, 50, 200)] n: usize,
+        #[values(0, 1, 2)] seed: u64
+    ) {
+        let points = random_points(n, seed);
+        let delaunay = DelaunayTriangulation::new(points.clone());
+        let triangulation = delaunay.triangulation();
+
+        for (p1, p2, p3) in triangulation.to_points() {
+            let triangle = Triangle::new(&p1, &p2, &p3);
+            assert!(triangle.area() > 0.0);
+            for p in points.iter() {
+                assert!(!triangle.in_circle(p));
+            }
+        }
+
+        // Triangulation of points in general position always has
+        // 2n - 2 - h triangles, where h is the number of hull vertices
+        let hull = MonotoneChain.convex_hull_of_points(&points).unwrap();
+        assert_eq!(triangulation.len(), 2 * n - 2 - hull.num_vertices());
+    }
+}
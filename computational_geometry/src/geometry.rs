@@ -0,0 +1,238 @@
+use std::cmp::Ordering;
+
+use crate::{
+    bounding_box::BoundingBox,
+    point::Point,
+    vector::Vector2,
+};
+
+
+/// Common interface for geometric objects defined by a set of points.
+///
+/// Implementors provide `points` and `contains_point`, everything else
+/// has a default implementation derived from `points`. No ordering of
+/// the points is assumed, so defaults only depend on the point set.
+///
+/// ```
+/// use computational_geometry::{geometry::Geometry, point::Point};
+///
+/// struct PointSet(Vec<Point>);
+///
+/// impl Geometry for PointSet {
+///     fn points(&self) -> Vec<&Point> {
+///         self.0.iter().collect()
+///     }
+///
+///     fn contains_point(&self, p: &Point) -> bool {
+///         self.0.contains(p)
+///     }
+/// }
+///
+/// let set = PointSet(vec![
+///     Point::new(0.0, 0.0),
+///     Point::new(2.0, -1.0),
+///     Point::new(1.0, 3.0),
+/// ]);
+/// assert!(set.contains_point(&Point::new(2.0, -1.0)));
+/// assert_eq!(set.bounding_box().max_y, 3.0);
+/// assert_eq!(set.lowest_rightmost_point(), Some(&Point::new(2.0, -1.0)));
+/// assert_eq!(set.highest_leftmost_point(), Some(&Point::new(1.0, 3.0)));
+/// ```
+pub trait Geometry {
+    /// All points defining the geometry, e.g. the vertices of a polygon.
+    fn points(&self) -> Vec<&Point>;
+
+    /// Whether the point is inside the geometry, where points on the
+    /// boundary are considered contained.
+    fn contains_point(&self, p: &Point) -> bool;
+
+    /// Point furthest in the given direction, which needn't be a unit
+    /// vector. Ties are broken by the point furthest a quarter turn CCW
+    /// from the direction, so the extremal points of a convex polygon
+    /// in successive directions go around it CCW.
+    fn extremal_point(&self, direction: Vector2) -> Option<&Point> {
+        self.points().into_iter()
+            .max_by(|a, b| compare_in_direction(a, b, &direction))
+    }
+
+    /// Point with minimum y, ties broken by maximum x. This is a common
+    /// starting point for hull algorithms since it is always on the hull.
+    fn lowest_rightmost_point(&self) -> Option<&Point> {
+        self.extremal_point(Vector2::new(0.0, -1.0))
+    }
+
+    /// Point with maximum y, ties broken by minimum x.
+    fn highest_leftmost_point(&self) -> Option<&Point> {
+        self.extremal_point(Vector2::new(0.0, 1.0))
+    }
+
+    /// Smallest axis-aligned box containing all of the points.
+    fn bounding_box(&self) -> BoundingBox {
+        let mut bb = BoundingBox::new(f64::MAX, f64::MIN, f64::MAX, f64::MIN);
+        for p in self.points() {
+            bb.min_x = bb.min_x.min(p.x);
+            bb.max_x = bb.max_x.max(p.x);
+            bb.min_y = bb.min_y.min(p.y);
+            bb.max_y = bb.max_y.max(p.y);
+        }
+        bb
+    }
+}
+
+
+pub(crate) fn compare_in_direction(a: &Point, b: &Point, direction: &Vector2) -> Ordering {
+    // Ordering used by `Geometry::extremal_point`, greater is further
+    let along = |p: &Point| Vector2::new(p.x, p.y).dot(direction);
+    let across = |p: &Point| Vector2::new(p.x, p.y).dot(&direction.perpendicular());
+    along(a).total_cmp(&along(b)).then(across(a).total_cmp(&across(b)))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        line_segment::LineSegment,
+        polygon::Polygon,
+        triangle::Triangle,
+    };
+
+    #[test]
+    fn test_bounding_box_polygon() {
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(5.0, -1.0),
+            Point::new(7.0, 6.0),
+            Point::new(-4.0, 8.0),
+            Point::new(-2.0, -3.0),
+        ];
+        let polygon = Polygon::new(points);
+        let expected = BoundingBox::new(-4.0, 7.0, -3.0, 8.0);
+        assert_eq!(polygon.bounding_box(), expected);
+    }
+
+    #[test]
+    fn test_bounding_box_triangle() {
+        let a = Point::new(1.0, 2.0);
+        let b = Point::new(4.0, -1.0);
+        let c = Point::new(2.0, 5.0);
+        let triangle = Triangle::new(&a, &b, &c);
+        let expected = BoundingBox::new(1.0, 4.0, -1.0, 5.0);
+        assert_eq!(triangle.bounding_box(), expected);
+    }
+
+    fn assert_contains<G: Geometry>(geometry: &G, inside: &[Point], outside: &[Point]) {
+        for p in inside {
+            assert!(geometry.contains_point(p), "expected {p:?} to be contained");
+        }
+        for p in outside {
+            assert!(!geometry.contains_point(p), "expected {p:?} to not be contained");
+        }
+    }
+
+    #[test]
+    fn test_contains_point_polygon() {
+        let polygon = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(2.0, 1.0),
+            Point::new(0.0, 4.0),
+        ]);
+        let inside = [
+            Point::new(1.0, 1.0),
+            Point::new(3.0, 2.0),
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(0.0, 2.0),
+            Point::new(2.0, 1.0),
+        ];
+        let outside = [
+            Point::new(2.0, 2.0),
+            Point::new(5.0, 1.0),
+            Point::new(-1.0, 0.0),
+            Point::new(2.0, -0.5),
+        ];
+        assert_contains(&polygon, &inside, &outside);
+    }
+
+    #[test]
+    fn test_contains_point_triangle() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(4.0, 0.0);
+        let c = Point::new(0.0, 4.0);
+        let inside = [
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 3.0),
+        ];
+        let outside = [
+            Point::new(3.0, 3.0),
+            Point::new(-1.0, 1.0),
+            Point::new(5.0, 0.0),
+        ];
+        // Containment shouldn't depend on the triangle's orientation
+        assert_contains(&Triangle::new(&a, &b, &c), &inside, &outside);
+        assert_contains(&Triangle::new(&a, &c, &b), &inside, &outside);
+    }
+
+    #[test]
+    fn test_contains_point_degenerate_triangle() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(2.0, 2.0);
+        let c = Point::new(4.0, 4.0);
+        let inside = [Point::new(1.0, 1.0), Point::new(4.0, 4.0)];
+        let outside = [Point::new(5.0, 5.0), Point::new(1.0, 0.0)];
+        assert_contains(&Triangle::new(&a, &b, &c), &inside, &outside);
+    }
+
+    #[test]
+    fn test_contains_point_line_segment() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(4.0, 2.0);
+        let inside = [Point::new(0.0, 0.0), Point::new(2.0, 1.0), Point::new(4.0, 2.0)];
+        let outside = [Point::new(6.0, 3.0), Point::new(-2.0, -1.0), Point::new(2.0, 0.0)];
+        assert_contains(&LineSegment::new(&a, &b), &inside, &outside);
+    }
+
+    #[test]
+    fn test_extreme_points() {
+        // Ties in y are broken towards the right for the lowest point
+        // and towards the left for the highest point
+        let polygon = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 3.0),
+            Point::new(0.0, 3.0),
+        ]);
+        assert_eq!(polygon.lowest_rightmost_point(), Some(&Point::new(4.0, 0.0)));
+        assert_eq!(polygon.highest_leftmost_point(), Some(&Point::new(0.0, 3.0)));
+    }
+
+    #[test]
+    fn test_extremal_point() {
+        let polygon = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 3.0),
+            Point::new(0.0, 3.0),
+        ]);
+        // Ties go to the point a quarter turn CCW from the direction
+        assert_eq!(polygon.extremal_point(Vector2::new(1.0, 0.0)), Some(&Point::new(4.0, 3.0)));
+        assert_eq!(polygon.extremal_point(Vector2::new(-1.0, 0.0)), Some(&Point::new(0.0, 0.0)));
+        assert_eq!(polygon.extremal_point(Vector2::new(0.0, -1.0)), polygon.lowest_rightmost_point());
+        assert_eq!(polygon.extremal_point(Vector2::new(0.0, 2.0)), polygon.highest_leftmost_point());
+        assert_eq!(polygon.extremal_point(Vector2::new(1.0, -1.0)), Some(&Point::new(4.0, 0.0)));
+        assert_eq!(polygon.extremal_point(Vector2::new(-1.0, 0.1)), Some(&Point::new(0.0, 3.0)));
+    }
+
+    #[test]
+    fn test_bounding_box_line_segment() {
+        let a = Point::new(3.0, -2.0);
+        let b = Point::new(-1.0, 6.0);
+        let ab = LineSegment::new(&a, &b);
+        let expected = BoundingBox::new(-1.0, 3.0, -2.0, 6.0);
+        assert_eq!(ab.bounding_box(), expected);
+    }
+}
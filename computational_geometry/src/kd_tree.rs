@@ -0,0 +1,179 @@
+use crate::point::Point;
+
+
+// A 2d-tree over a static set of points. The tree is stored implicitly
+// in a single Vec, each subslice holds its splitting point at the middle
+// with the points below it on the splitting axis to the left and those
+// above to the right. Splits alternate between x (even depth) and y (odd
+// depth) so no node structs or pointers are needed.
+#[derive(Debug)]
+pub struct KdTree {
+    points: Vec<Point>,
+}
+
+impl KdTree {
+    pub fn new(mut points: Vec<Point>) -> Self {
+        build(&mut points, 0);
+        KdTree { points }
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn nearest(&self, query: &Point) -> Option<&Point> {
+        self.k_nearest(query, 1).into_iter().next()
+    }
+
+    pub fn k_nearest(&self, query: &Point, k: usize) -> Vec<&Point> {
+        // Closest points first, ties between equidistant points are
+        // broken arbitrarily. Fewer than k are returned if the tree
+        // doesn't have that many points.
+        let mut best = Vec::with_capacity(k + 1);
+        if k > 0 {
+            search(&self.points, 0, query, k, &mut best);
+        }
+        best.into_iter().map(|(_, p)| p).collect()
+    }
+}
+
+
+fn coordinate(p: &Point, depth: usize) -> f64 {
+    match depth % 2 {
+        0 => p.x,
+        _ => p.y,
+    }
+}
+
+fn build(points: &mut [Point], depth: usize) {
+    if points.len() <= 1 {
+        return;
+    }
+    let mid = points.len() / 2;
+    points.select_nth_unstable_by(mid, |a, b| {
+        coordinate(a, depth).total_cmp(&coordinate(b, depth))
+    });
+    let (left, right) = points.split_at_mut(mid);
+    build(left, depth + 1);
+    build(&mut right[1..], depth + 1);
+}
+
+fn search<'a>(
+    points: &'a [Point],
+    depth: usize,
+    query: &Point,
+    k: usize,
+    best: &mut Vec<(f64, &'a Point)>,
+) {
+    if points.is_empty() {
+        return;
+    }
+    let mid = points.len() / 2;
+    let p = &points[mid];
+
+    // Best candidates are kept sorted by squared distance, dropping the
+    // furthest once there are more than k of them
+    let d = query.distance_squared_to(p);
+    if best.len() < k || d < best[best.len() - 1].0 {
+        let i = best.partition_point(|(other, _)| *other <= d);
+        best.insert(i, (d, p));
+        best.truncate(k);
+    }
+
+    // Search the side of the split containing the query first, the other
+    // side can only have closer points if the splitting line is closer
+    // than the current k-th best
+    let diff = coordinate(query, depth) - coordinate(p, depth);
+    let (near, far) = match diff < 0.0 {
+        true  => (&points[..mid], &points[mid + 1..]),
+        false => (&points[mid + 1..], &points[..mid]),
+    };
+    search(near, depth + 1, query, k, best);
+    if best.len() < k || diff * diff < best[best.len() - 1].0 {
+        search(far, depth + 1, query, k, best);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use rstest::rstest;
+
+    fn random_points(n: usize, rng: &mut StdRng) -> Vec<Point> {
+        (0..n)
+            .map(|_| Point::new(rng.gen_range(-100.0..100.0), rng.gen_range(-100.0..100.0)))
+            .collect()
+    }
+
+    fn brute_force_k_nearest<'a>(points: &'a [Point], query: &Point, k: usize) -> Vec<&'a Point> {
+        let mut sorted = points.iter().collect::<Vec<_>>();
+        sorted.sort_by(|a, b| {
+            query.distance_squared_to(a).total_cmp(&query.distance_squared_to(b))
+        });
+        sorted.truncate(k);
+        sorted
+    }
+
+    #[test]
+    fn test_empty() {
+        let tree = KdTree::new(Vec::new());
+        assert!(tree.is_empty());
+        assert_eq!(tree.nearest(&Point::new(0.0, 0.0)), None);
+        assert!(tree.k_nearest(&Point::new(0.0, 0.0), 3).is_empty());
+    }
+
+    #[test]
+    fn test_nearest() {
+        let tree = KdTree::new(vec![
+            Point::new(2.0, 3.0),
+            Point::new(5.0, 4.0),
+            Point::new(9.0, 6.0),
+            Point::new(4.0, 7.0),
+            Point::new(8.0, 1.0),
+            Point::new(7.0, 2.0),
+        ]);
+        assert_eq!(tree.len(), 6);
+        assert_eq!(tree.nearest(&Point::new(9.0, 2.0)), Some(&Point::new(8.0, 1.0)));
+        assert_eq!(tree.nearest(&Point::new(4.0, 7.0)), Some(&Point::new(4.0, 7.0)));
+        assert_eq!(
+            tree.k_nearest(&Point::new(6.0, 3.5), 3),
+            vec![&Point::new(5.0, 4.0), &Point::new(7.0, 2.0), &Point::new(8.0, 1.0)]
+        );
+        assert_eq!(tree.k_nearest(&Point::new(6.0, 3.5), 0).len(), 0);
+        assert_eq!(tree.k_nearest(&Point::new(6.0, 3.5), 10).len(), 6);
+    }
+
+    #[test]
+    fn test_duplicate_points() {
+        let p = Point::new(1.0, 1.0);
+        let tree = KdTree::new(vec![p.clone(); 5]);
+        assert_eq!(tree.k_nearest(&Point::new(0.0, 0.0), 3), vec![&p; 3]);
+    }
+
+    #[rstest]
+    fn test_matches_brute_force(
+        #[values(1, 2, 10, 100, 1000)] n: usize,
+        #[values(1, 5)] k: usize,
+        #[values(0, 1, 2)] seed: u64
+    ) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let points = random_points(n, &mut rng);
+        let tree = KdTree::new(points.clone());
+        for query in random_points(50, &mut rng) {
+            // Compare distances since equidistant points may be ordered
+            // differently, which is vanishingly rare for random inputs
+            let distances = |v: Vec<&Point>| v.iter()
+                .map(|p| query.distance_squared_to(p))
+                .collect::<Vec<_>>();
+            let expected = brute_force_k_nearest(&points, &query, k);
+            assert_eq!(distances(tree.k_nearest(&query, k)), distances(expected.clone()));
+            assert_eq!(tree.nearest(&query), Some(expected[0]));
+        }
+    }
+}
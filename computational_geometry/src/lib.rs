@@ -6,10 +6,27 @@
 // empirical precision limit on the entire test suite
 const F64_ASSERT_PRECISION: f64 = 1e-4f64;
 
+pub mod boolean;
 pub mod bounding_box;
+pub mod closest_pair;
+pub mod containment_index;
+pub mod convex_hull;
+pub mod dcel;
+pub mod delaunay;
+pub mod geometry;
+pub mod kd_tree;
 pub mod line_segment;
+mod monotone;
 pub mod point;
+pub mod point_locator;
 pub mod polygon;
+pub mod polygon_with_holes;
+mod predicates;
+pub mod scalar;
+pub mod segment_intersection;
+#[cfg(test)]
+mod test_util;
 pub mod triangle;
+pub mod vector;
 pub mod vertex;
 pub mod vertex_map;
\ No newline at end of file
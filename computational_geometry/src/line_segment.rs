@@ -1,29 +1,72 @@
+use std::f64::consts::TAU;
+
 use crate::{
+    geometry::Geometry,
     point::Point,
+    scalar::Scalar,
     triangle::Triangle,
+    vector::Vector2,
     vertex::Vertex,
 };
 
 
 #[derive(Debug, PartialEq)]
-pub struct LineSegment<'a> {
-    pub p1: &'a Point,
-    pub p2: &'a Point,
+pub enum IntersectionKind<'a, T: Scalar = f64> {
+    // Segments do not intersect
+    None,
+    // Segments cross at a single point interior to both of them
+    Proper(Point<T>),
+    // Segments meet at a single point that is an endpoint of at
+    // least one of them, e.g. a shared endpoint or a T-junction
+    Endpoint(Point<T>),
+    // Segments are collinear and overlap along this sub-segment
+    Collinear(LineSegment<'a, T>),
 }
 
-impl<'a> LineSegment<'a> {
-    pub fn new(p1: &'a Point, p2: &'a Point) -> Self {
+
+#[derive(Debug, PartialEq)]
+pub struct LineSegment<'a, T = f64> {
+    pub p1: &'a Point<T>,
+    pub p2: &'a Point<T>,
+}
+
+impl<'a, T> LineSegment<'a, T> {
+    pub fn new(p1: &'a Point<T>, p2: &'a Point<T>) -> Self {
         LineSegment { p1, p2 }
     }
+}
 
-    pub fn from_vertices(v1: &'a Vertex, v2: &'a Vertex) -> Self {
-        LineSegment::new(&v1.coords, &v2.coords)
+impl<'a, T: Scalar> LineSegment<'a, T> {
+    pub fn from_vertices(v1: &'a Vertex<T>, v2: &'a Vertex<T>) -> Self {
+        LineSegment::new(v1.point(), v2.point())
     }
 
-    pub fn reverse(&self) -> LineSegment {
+    pub fn reverse(&self) -> LineSegment<'_, T> {
         LineSegment::new(self.p2, self.p1)
     }
     
+    pub fn length(&self) -> T {
+        self.p1.distance_to(self.p2)
+    }
+
+    pub fn length_squared(&self) -> T {
+        // Cheaper than length when only comparing lengths
+        self.p1.distance_squared_to(self.p2)
+    }
+
+    pub fn midpoint(&self) -> Point<T> {
+        self.point_at(T::from_f64(0.5))
+    }
+
+    pub fn point_at(&self, t: T) -> Point<T> {
+        // Interpolates from p1 at t = 0 to p2 at t = 1, values outside
+        // of [0, 1] extrapolate along the line through the segment
+        Point::new(
+            self.p1.x + t * (self.p2.x - self.p1.x),
+            self.p1.y + t * (self.p2.y - self.p1.y),
+        )
+    }
+
     pub fn is_vertical(&self) -> bool {
         self.p1.x == self.p2.x
     }
@@ -32,7 +75,7 @@ impl<'a> LineSegment<'a> {
         self.p1.y == self.p2.y
     }
 
-    pub fn proper_intersects(&self, cd: &LineSegment) -> bool {    
+    pub fn proper_intersects(&self, cd: &LineSegment<T>) -> bool {    
         let a = self.p1;
         let b = self.p2;
         let c = cd.p1;
@@ -58,7 +101,7 @@ impl<'a> LineSegment<'a> {
         ab_splits_cd && cd_splits_ab
     }
         
-    pub fn improper_intersects(&self, cd: &LineSegment) -> bool {
+    pub fn improper_intersects(&self, cd: &LineSegment<T>) -> bool {
         let a = self.p1;
         let b = self.p2;
         let c = cd.p1;
@@ -67,23 +110,164 @@ impl<'a> LineSegment<'a> {
         c.between(a, b) || d.between(a, b) || a.between(c, d) || b.between(c, d)
     }
     
-    pub fn intersects(&self, cd: &LineSegment) -> bool {
-        self.proper_intersects(cd) || self.improper_intersects(cd)
+    pub fn intersects(&self, cd: &LineSegment<T>) -> bool {
+        // Same as proper or improper intersection, so like
+        // `Point::between` a single shared point that's at the larger
+        // coordinate of both segments doesn't count
+        match self.classify_intersection(cd) {
+            IntersectionKind::None => false,
+            IntersectionKind::Proper(_) => true,
+            IntersectionKind::Collinear(_) => true,
+            IntersectionKind::Endpoint(p) => p.between(self.p1, self.p2) || p.between(cd.p1, cd.p2),
+        }
     }
 
-    pub fn connected_to(&self, cd: &LineSegment) -> bool {
+    pub fn intersection(&self, cd: &LineSegment<T>) -> Option<Point<T>> {
+        // For collinear overlap the lexicographically smallest endpoint
+        // of the overlap is returned so the result is order-independent
+        match self.classify_intersection(cd) {
+            IntersectionKind::None => None,
+            IntersectionKind::Proper(p) => Some(p),
+            IntersectionKind::Endpoint(p) => Some(p),
+            IntersectionKind::Collinear(overlap) => Some(overlap.p1.clone()),
+        }
+    }
+
+    pub fn classify_intersection(&self, cd: &LineSegment<'a, T>) -> IntersectionKind<'a, T> {
+        let a = self.p1;
+        let b = self.p2;
+        let c = cd.p1;
+        let d = cd.p2;
+
+        if self.proper_intersects(cd) {
+            // Solve a + s * (b - a) = c + t * (d - c) for s, which is 
+            // well-defined since proper intersections are not parallel
+            let denom = (b.x - a.x) * (d.y - c.y) - (b.y - a.y) * (d.x - c.x);
+            let s = ((c.x - a.x) * (d.y - c.y) - (c.y - a.y) * (d.x - c.x)) / denom;
+            let x = a.x + s * (b.x - a.x);
+            let y = a.y + s * (b.y - a.y);
+            return IntersectionKind::Proper(Point::new(x, y));
+        }
+
+        // Otherwise the segments can only intersect if an endpoint of 
+        // one lies on the other. This covers shared endpoints, one 
+        // segment touching the other, and collinear overlap.
+        let mut on_other = [c, d].into_iter()
+            .filter(|p| self.on_segment(p))
+            .chain([a, b].into_iter().filter(|p| cd.on_segment(p)))
+            .collect::<Vec<_>>();
+        on_other.sort_by(|p, q| p.x.total_cmp(&q.x).then(p.y.total_cmp(&q.y)));
+        on_other.dedup();
+
+        match on_other.as_slice() {
+            [] => IntersectionKind::None,
+            [p] => IntersectionKind::Endpoint((*p).clone()),
+            [first, .., last] => IntersectionKind::Collinear(LineSegment::new(first, last)),
+        }
+    }
+
+    fn on_segment(&self, p: &Point<T>) -> bool {
+        // Inclusive of the endpoints, unlike Point::between
+        Triangle::new(self.p1, self.p2, p).has_collinear_points()
+            && p.x >= self.p1.x.min(self.p2.x)
+            && p.x <= self.p1.x.max(self.p2.x)
+            && p.y >= self.p1.y.min(self.p2.y)
+            && p.y <= self.p1.y.max(self.p2.y)
+    }
+
+    pub fn connected_to(&self, cd: &LineSegment<T>) -> bool {
         self.incident_to(cd.p1) || self.incident_to(cd.p2)
     }
 
-    pub fn incident_to(&self, p: &Point) -> bool {
+    pub fn incident_to(&self, p: &Point<T>) -> bool {
         self.p1 == p || self.p2 == p
     }
 }
 
+// Directions, angles and distances are only for f64
+impl<'a> LineSegment<'a> {
+    pub fn direction(&self) -> Vector2 {
+        // Unit vector from p1 to p2, NaN for a zero-length segment
+        Vector2::between(self.p1, self.p2).normalized()
+    }
+
+    pub fn normal(&self) -> Vector2 {
+        // Unit vector perpendicular to the direction on its left side,
+        // which points into a CCW polygon from each of its edges
+        self.direction().perpendicular()
+    }
+
+    pub fn angle(&self) -> f64 {
+        // Angle of the direction CCW from the positive x-axis in [0, 2pi)
+        let d = self.direction();
+        let angle = d.y.atan2(d.x);
+        if angle >= 0.0 {
+            return angle;
+        }
+        // Tiny negative angles can round up to 2pi
+        let angle = angle + TAU;
+        if angle < TAU { angle } else { 0.0 }
+    }
+
+    pub fn angle_between(&self, other: &LineSegment) -> f64 {
+        // Unsigned angle between the directions in [0, pi], so parallel
+        // segments give 0 and anti-parallel segments give pi
+        let d1 = self.direction();
+        let d2 = other.direction();
+        d1.cross(&d2).atan2(d1.dot(&d2)).abs()
+    }
+
+    pub fn distance_to_vertex(&self, v: &Vertex) -> f64 {
+        // Distance to the line, see `distance_to_line`
+        self.distance_to_line(v.point())
+    }
+
+    pub fn distance_to_point(&self, p: &Point) -> f64 {
+        // Distance to the segment, see `distance_to_segment`
+        self.distance_to_segment(p)
+    }
+
+    pub fn distance_to_line(&self, p: &Point) -> f64 {
+        // Perpendicular distance from the point to the (infinite) line
+        // through the segment, computed from twice the triangle area
+        // over the base length. Assumes the segment is non-degenerate.
+        let area = Triangle::new(self.p1, self.p2, p).area();
+        2.0 * area.abs() / self.length()
+    }
+
+    pub fn distance_to_segment(&self, p: &Point) -> f64 {
+        // Distance from the point to the closest point of the segment,
+        // which is an endpoint if the point's projection onto the line
+        // falls outside the segment. Degenerate segments are treated as
+        // the single point.
+        let length_squared = self.length_squared();
+        if length_squared == 0.0 {
+            return p.distance_to(self.p1);
+        }
+        let ab = Vector2::between(self.p1, self.p2);
+        let t = Vector2::between(self.p1, p).dot(&ab) / length_squared;
+        p.distance_to(&self.point_at(t.clamp(0.0, 1.0)))
+    }
+}
+
+impl Geometry for LineSegment<'_> {
+    fn points(&self) -> Vec<&Point> {
+        vec![self.p1, self.p2]
+    }
+
+    fn contains_point(&self, p: &Point) -> bool {
+        self.incident_to(p) || p.between(self.p1, self.p2)
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use assert_approx_eq::assert_approx_eq;
+    use rstest::rstest;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI, SQRT_2};
+    use crate::F64_ASSERT_PRECISION;
 
     #[test]
     fn test_proper_intersect() {
@@ -121,6 +305,46 @@ mod tests {
         assert!( cd.intersects(&ab));
     }
 
+    #[test]
+    fn test_intersects_shared_endpoint() {
+        // Half-open like `Point::between`, a shared endpoint only counts
+        // when it's at the smaller coordinate of the other segment, though
+        // it's always classified as an endpoint intersection
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(2.0, 0.0);
+        let c = Point::new(2.0, 2.0);
+        let d = Point::new(4.0, 1.0);
+
+        let ab = LineSegment::new(&a, &b);
+        let bd = LineSegment::new(&b, &d);
+        assert!( ab.intersects(&bd));
+        assert!( bd.intersects(&ab));
+
+        let cb = LineSegment::new(&c, &b);
+        let ca = LineSegment::new(&c, &a);
+        assert_eq!(ca.classify_intersection(&cb), IntersectionKind::Endpoint(c.clone()));
+        assert!(!ca.intersects(&cb));
+        assert!(!cb.intersects(&ca));
+    }
+
+    #[test]
+    fn test_intersects_matches_proper_or_improper() {
+        // Every pair of segments between points of a small grid, which
+        // covers shared endpoints, touching, overlap and zero length
+        let points = (0..3)
+            .flat_map(|x| (0..3).map(move |y| Point::new(x as f64, y as f64)))
+            .collect::<Vec<_>>();
+        let segments = points.iter()
+            .flat_map(|p| points.iter().map(move |q| LineSegment::new(p, q)))
+            .collect::<Vec<_>>();
+        for ab in segments.iter() {
+            for cd in segments.iter() {
+                let expected = ab.proper_intersects(cd) || ab.improper_intersects(cd);
+                assert_eq!(ab.intersects(cd), expected, "{ab:?} {cd:?}");
+            }
+        }
+    }
+
     #[test]
     fn test_no_intersect() {
         let a = Point::new(6.0, 4.0);
@@ -150,6 +374,111 @@ mod tests {
         assert!( ab.intersects(&ab));
     }
 
+    #[test]
+    fn test_intersection_crossing() {
+        let a = Point::new(6.0, 4.0);
+        let b = Point::new(0.0, 4.0);
+        let c = Point::new(1.0, 0.0);
+        let d = Point::new(4.0, 6.0);
+        let ab = LineSegment::new(&a, &b);
+        let cd = LineSegment::new(&c, &d);
+        let expected = Point::new(3.0, 4.0);
+        assert_eq!(ab.intersection(&cd), Some(expected.clone()));
+        assert_eq!(cd.intersection(&ab), Some(expected));
+    }
+
+    #[test]
+    fn test_intersection_touching() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(4.0, 0.0);
+        let c = Point::new(2.0, 0.0);
+        let d = Point::new(2.0, 3.0);
+        let ab = LineSegment::new(&a, &b);
+        let cd = LineSegment::new(&c, &d);
+        assert_eq!(ab.intersection(&cd), Some(c.clone()));
+        assert_eq!(cd.intersection(&ab), Some(c));
+    }
+
+    #[test]
+    fn test_intersection_shared_endpoint() {
+        // Shared endpoint is the max x-value for both segments, which
+        // is not detected by Point::between
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(1.0, 1.0);
+        let c = Point::new(1.0, 1.0);
+        let d = Point::new(0.0, 2.0);
+        let ab = LineSegment::new(&a, &b);
+        let cd = LineSegment::new(&c, &d);
+        assert!(ab.connected_to(&cd));
+        assert_eq!(ab.intersection(&cd), Some(b.clone()));
+        assert_eq!(cd.intersection(&ab), Some(b));
+    }
+
+    #[test]
+    fn test_intersection_parallel() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(4.0, 2.0);
+        let c = Point::new(0.0, 1.0);
+        let d = Point::new(4.0, 3.0);
+        let ab = LineSegment::new(&a, &b);
+        let cd = LineSegment::new(&c, &d);
+        assert_eq!(ab.intersection(&cd), None);
+        assert_eq!(cd.intersection(&ab), None);
+    }
+
+    #[test]
+    fn test_intersection_collinear_overlapping() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(4.0, 0.0);
+        let c = Point::new(2.0, 0.0);
+        let d = Point::new(6.0, 0.0);
+        let ab = LineSegment::new(&a, &b);
+        let cd = LineSegment::new(&c, &d);
+        assert_eq!(ab.intersection(&cd), Some(c.clone()));
+        assert_eq!(cd.intersection(&ab), Some(c));
+    }
+
+    #[test]
+    fn test_intersection_collinear_disjoint() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(2.0, 0.0);
+        let c = Point::new(3.0, 0.0);
+        let d = Point::new(6.0, 0.0);
+        let ab = LineSegment::new(&a, &b);
+        let cd = LineSegment::new(&c, &d);
+        assert_eq!(ab.intersection(&cd), None);
+        assert_eq!(cd.intersection(&ab), None);
+    }
+
+    #[test]
+    fn test_classify_intersection() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(4.0, 0.0);
+        let c = Point::new(2.0, -2.0);
+        let d = Point::new(2.0, 2.0);
+        let e = Point::new(2.0, 0.0);
+        let f = Point::new(6.0, 0.0);
+        let g = Point::new(0.0, 1.0);
+        let h = Point::new(4.0, 1.0);
+
+        let ab = LineSegment::new(&a, &b);
+        let cd = LineSegment::new(&c, &d);
+        let ed = LineSegment::new(&e, &d);
+        let bd = LineSegment::new(&b, &d);
+        let ef = LineSegment::new(&e, &f);
+        let bf = LineSegment::new(&b, &f);
+        let gh = LineSegment::new(&g, &h);
+
+        assert_eq!(ab.classify_intersection(&cd), IntersectionKind::Proper(e.clone()));
+        assert_eq!(ab.classify_intersection(&ed), IntersectionKind::Endpoint(e.clone()));
+        assert_eq!(ab.classify_intersection(&bd), IntersectionKind::Endpoint(b.clone()));
+        assert_eq!(ab.classify_intersection(&bf), IntersectionKind::Endpoint(b.clone()));
+        assert_eq!(ab.classify_intersection(&ef), IntersectionKind::Collinear(LineSegment::new(&e, &b)));
+        assert_eq!(ef.classify_intersection(&ab), IntersectionKind::Collinear(LineSegment::new(&e, &b)));
+        assert_eq!(ab.classify_intersection(&ab), IntersectionKind::Collinear(LineSegment::new(&a, &b)));
+        assert_eq!(ab.classify_intersection(&gh), IntersectionKind::None);
+    }
+
     #[test]
     fn test_reverse() {
         let a = Point::new(0.0, 0.0);
@@ -161,4 +490,162 @@ mod tests {
         assert_eq!(ba.p1, &b);
         assert_eq!(ba.p2, &a);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_distance_to_vertex() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(4.0, 0.0);
+        let ab = LineSegment::new(&a, &b);
+        let above = Vertex::new(Point::new(1.0, 3.0), 0usize.into(), 0usize.into(), 0usize.into());
+        let beyond = Vertex::new(Point::new(7.0, -2.0), 0usize.into(), 0usize.into(), 0usize.into());
+        let on = Vertex::new(Point::new(2.0, 0.0), 0usize.into(), 0usize.into(), 0usize.into());
+        assert_eq!(ab.distance_to_vertex(&above), 3.0);
+        assert_eq!(ab.distance_to_vertex(&beyond), 2.0);
+        assert_eq!(ab.distance_to_vertex(&on), 0.0);
+    }
+
+    #[rstest]
+    #[case(Point::new(1.0, 3.0), 3.0, 3.0)]
+    #[case(Point::new(2.0, -1.5), 1.5, 1.5)]
+    #[case(Point::new(2.0, 0.0), 0.0, 0.0)]
+    // Beyond the endpoints the segment distance is to the nearest one
+    #[case(Point::new(7.0, -4.0), 4.0, 5.0)]
+    #[case(Point::new(-1.0, 0.0), 0.0, 1.0)]
+    #[case(Point::new(4.0, 2.0), 2.0, 2.0)]
+    fn test_distance_to_line_and_segment(
+        #[case] p: Point,
+        #[case] line_distance: f64,
+        #[case] segment_distance: f64
+    ) {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(4.0, 0.0);
+        let ab = LineSegment::new(&a, &b);
+        assert_approx_eq!(ab.distance_to_line(&p), line_distance, F64_ASSERT_PRECISION);
+        assert_approx_eq!(ab.distance_to_segment(&p), segment_distance, F64_ASSERT_PRECISION);
+        assert_eq!(ab.distance_to_point(&p), ab.distance_to_segment(&p));
+        assert_eq!(ab.reverse().distance_to_segment(&p), ab.distance_to_segment(&p));
+    }
+
+    #[test]
+    fn test_distance_to_degenerate_segment() {
+        let a = Point::new(1.0, 1.0);
+        let aa = LineSegment::new(&a, &a);
+        assert_eq!(aa.distance_to_segment(&Point::new(4.0, 5.0)), 5.0);
+    }
+
+    #[test]
+    fn test_point_at() {
+        let a = Point::new(1.0, 2.0);
+        let b = Point::new(5.0, -2.0);
+        let ab = LineSegment::new(&a, &b);
+        assert_eq!(ab.point_at(0.0), a);
+        assert_eq!(ab.point_at(0.5), Point::new(3.0, 0.0));
+        assert_eq!(ab.point_at(1.0), b);
+        assert_eq!(ab.point_at(1.5), Point::new(7.0, -4.0));
+        assert_eq!(ab.point_at(-0.25), Point::new(0.0, 3.0));
+        assert_eq!(ab.midpoint(), ab.point_at(0.5));
+    }
+
+    #[rstest]
+    #[case(Point::new(1.0, 1.0), Point::new(3.0, 1.0), 0.0)]
+    #[case(Point::new(1.0, 1.0), Point::new(3.0, 3.0), FRAC_PI_4)]
+    #[case(Point::new(1.0, 1.0), Point::new(1.0, 3.0), FRAC_PI_2)]
+    #[case(Point::new(1.0, 1.0), Point::new(-1.0, 1.0), PI)]
+    #[case(Point::new(1.0, 1.0), Point::new(1.0, -1.0), 3.0 * FRAC_PI_2)]
+    #[case(Point::new(1.0, 1.0), Point::new(3.0, -1.0), 7.0 * FRAC_PI_4)]
+    fn test_angle(#[case] a: Point, #[case] b: Point, #[case] expected: f64) {
+        let ab = LineSegment::new(&a, &b);
+        assert_approx_eq!(ab.angle(), expected, F64_ASSERT_PRECISION);
+        // Reversing the segment is a half turn
+        let reversed = (expected + PI) % TAU;
+        assert_approx_eq!(ab.reverse().angle(), reversed, F64_ASSERT_PRECISION);
+    }
+
+    #[test]
+    fn test_angle_range() {
+        // Just below the positive x-axis wraps to just under 2pi, never 2pi
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(1.0, -1e-300);
+        let angle = LineSegment::new(&a, &b).angle();
+        assert!((0.0..TAU).contains(&angle));
+    }
+
+    #[rstest]
+    // Perpendicular
+    #[case(Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(1.0, -1.0), Point::new(1.0, 3.0), FRAC_PI_2)]
+    #[case(Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(1.0, 3.0), Point::new(1.0, -1.0), FRAC_PI_2)]
+    #[case(Point::new(0.0, 0.0), Point::new(1.0, 1.0), Point::new(5.0, 5.0), Point::new(6.0, 4.0), FRAC_PI_2)]
+    // Anti-parallel
+    #[case(Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(5.0, 1.0), Point::new(4.0, 1.0), PI)]
+    #[case(Point::new(0.0, 0.0), Point::new(1.0, 1.0), Point::new(3.0, 3.0), Point::new(2.0, 2.0), PI)]
+    // Parallel
+    #[case(Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(0.0, 1.0), Point::new(7.0, 1.0), 0.0)]
+    // Oblique
+    #[case(Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(0.0, 0.0), Point::new(-1.0, 1.0), 3.0 * FRAC_PI_4)]
+    fn test_angle_between(
+        #[case] a: Point,
+        #[case] b: Point,
+        #[case] c: Point,
+        #[case] d: Point,
+        #[case] expected: f64
+    ) {
+        let ab = LineSegment::new(&a, &b);
+        let cd = LineSegment::new(&c, &d);
+        assert_approx_eq!(ab.angle_between(&cd), expected, F64_ASSERT_PRECISION);
+        assert_approx_eq!(cd.angle_between(&ab), expected, F64_ASSERT_PRECISION);
+    }
+
+    #[rstest]
+    #[case(Point::new(0.0, 0.0), Point::new(3.0, 0.0), (1.0, 0.0), (0.0, 1.0))]
+    #[case(Point::new(0.0, 0.0), Point::new(-2.0, 0.0), (-1.0, 0.0), (0.0, -1.0))]
+    #[case(Point::new(1.0, 1.0), Point::new(1.0, 4.0), (0.0, 1.0), (-1.0, 0.0))]
+    #[case(Point::new(1.0, 1.0), Point::new(1.0, -4.0), (0.0, -1.0), (1.0, 0.0))]
+    #[case(Point::new(0.0, 0.0), Point::new(2.0, 2.0), (0.5 * SQRT_2, 0.5 * SQRT_2), (-0.5 * SQRT_2, 0.5 * SQRT_2))]
+    #[case(Point::new(1.0, 1.0), Point::new(4.0, -3.0), (0.6, -0.8), (0.8, 0.6))]
+    fn test_direction_normal(
+        #[case] a: Point,
+        #[case] b: Point,
+        #[case] direction: (f64, f64),
+        #[case] normal: (f64, f64)
+    ) {
+        let ab = LineSegment::new(&a, &b);
+        let d = ab.direction();
+        let n = ab.normal();
+        assert_approx_eq!(d.x, direction.0, F64_ASSERT_PRECISION);
+        assert_approx_eq!(d.y, direction.1, F64_ASSERT_PRECISION);
+        assert_approx_eq!(n.x, normal.0, F64_ASSERT_PRECISION);
+        assert_approx_eq!(n.y, normal.1, F64_ASSERT_PRECISION);
+        assert_approx_eq!(d.dot(&n), 0.0, F64_ASSERT_PRECISION);
+
+        // Stepping from the midpoint along the normal is left of the segment
+        assert!((&ab.midpoint() + n).left(&ab));
+    }
+
+    #[test]
+    fn test_length() {
+        let a = Point::new(1.0, 1.0);
+        let b = Point::new(4.0, 5.0);
+        let ab = LineSegment::new(&a, &b);
+        assert_eq!(ab.length(), 5.0);
+        assert_eq!(ab.length_squared(), 25.0);
+        assert_eq!(ab.reverse().length(), 5.0);
+    }
+
+    #[test]
+    fn test_f32_intersection() {
+        let a = Point::new(0.0f32, 0.0);
+        let b = Point::new(4.0f32, 4.0);
+        let c = Point::new(0.0f32, 4.0);
+        let d = Point::new(4.0f32, 0.0);
+        let ab = LineSegment::new(&a, &b);
+        let cd = LineSegment::new(&c, &d);
+        assert_eq!(ab.length_squared(), 32.0f32);
+        assert_eq!(ab.midpoint(), Point::new(2.0f32, 2.0));
+        assert!(ab.proper_intersects(&cd));
+        assert_eq!(ab.intersection(&cd), Some(Point::new(2.0f32, 2.0)));
+        assert_eq!(
+            ab.classify_intersection(&LineSegment::new(&b, &d)),
+            IntersectionKind::Endpoint(b.clone())
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use std::collections::HashSet;
+
+use crate::{
+    point::Point,
+    triangle::Triangle,
+};
+
+// Helpers for decomposing a polygon into y-monotone pieces and then
+// triangulating those pieces, following the sweep-line approach in
+// de Berg et al. "Computational Geometry: Algorithms and Applications"
+// (Ch. 3). All functions operate on indices into a list of points
+// that are assumed to be the polygon boundary in CCW order.
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum VertexType {
+    Start,
+    End,
+    Split,
+    Merge,
+    Regular,
+}
+
+
+// Orders points top to bottom, where ties in y are broken by
+// treating the point with smaller x-value as being above
+fn above(p: &Point, q: &Point) -> bool {
+    p.y > q.y || (p.y == q.y && p.x < q.x)
+}
+
+fn sweep_order(points: &[&Point], indices: &[usize]) -> Vec<usize> {
+    let mut order = indices.to_vec();
+    order.sort_by(|&a, &b| {
+        points[b].y.total_cmp(&points[a].y)
+            .then(points[a].x.total_cmp(&points[b].x))
+    });
+    order
+}
+
+fn vertex_type(points: &[&Point], i: usize) -> VertexType {
+    let n = points.len();
+    let prev = points[(i + n - 1) % n];
+    let next = points[(i + 1) % n];
+    let v = points[i];
+    let convex = Triangle::new(prev, v, next).area() > 0.0;
+    match (above(prev, v), above(next, v)) {
+        (false, false) if convex => VertexType::Start,
+        (false, false) => VertexType::Split,
+        (true, true) if convex => VertexType::End,
+        (true, true) => VertexType::Merge,
+        _ => VertexType::Regular,
+    }
+}
+
+fn x_at(points: &[&Point], edge: usize, y: f64) -> f64 {
+    // Edge i goes from vertex i to vertex i + 1
+    let p1 = points[edge];
+    let p2 = points[(edge + 1) % points.len()];
+    if p1.y == p2.y {
+        return p1.x.max(p2.x);
+    }
+    p1.x + (y - p1.y) * (p2.x - p1.x) / (p2.y - p1.y)
+}
+
+fn edge_left_of(points: &[&Point], status: &[usize], v: usize) -> Option<usize> {
+    let p = points[v];
+    status.iter()
+        .map(|&e| (e, x_at(points, e, p.y)))
+        .filter(|(_, x)| *x <= p.x)
+        .max_by(|(_, x1), (_, x2)| x1.total_cmp(x2))
+        .map(|(e, _)| e)
+}
+
+pub(crate) fn monotone_diagonals(points: &[&Point]) -> Option<Vec<(usize, usize)>> {
+    // Sweeps top to bottom and adds a diagonal at each split and merge
+    // vertex so that the resulting pieces are all y-monotone. Returns
+    // None if the sweep gets into a state that is not possible for a
+    // valid simple polygon. The sweep status is a simple list of the
+    // edges that have the polygon interior to their right, so finding
+    // the edge left of a vertex is linear in the status size.
+    let n = points.len();
+    let indices = (0..n).collect::<Vec<_>>();
+    let types = indices.iter()
+        .map(|&i| vertex_type(points, i))
+        .collect::<Vec<_>>();
+    let is_merge = |i: usize| types[i] == VertexType::Merge;
+
+    let mut diagonals = Vec::new();
+    let mut status: Vec<usize> = Vec::new();
+    let mut helper = vec![0; n];
+
+    for v in sweep_order(points, &indices) {
+        let prev_edge = (v + n - 1) % n;
+        match types[v] {
+            VertexType::Start => {
+                status.push(v);
+                helper[v] = v;
+            }
+            VertexType::End => {
+                if is_merge(helper[prev_edge]) {
+                    diagonals.push((v, helper[prev_edge]));
+                }
+                status.retain(|&e| e != prev_edge);
+            }
+            VertexType::Split => {
+                let e = edge_left_of(points, &status, v)?;
+                diagonals.push((v, helper[e]));
+                helper[e] = v;
+                status.push(v);
+                helper[v] = v;
+            }
+            VertexType::Merge => {
+                if is_merge(helper[prev_edge]) {
+                    diagonals.push((v, helper[prev_edge]));
+                }
+                status.retain(|&e| e != prev_edge);
+                let e = edge_left_of(points, &status, v)?;
+                if is_merge(helper[e]) {
+                    diagonals.push((v, helper[e]));
+                }
+                helper[e] = v;
+            }
+            VertexType::Regular => {
+                if above(points[prev_edge], points[v]) {
+                    // Polygon interior is to the right of the vertex
+                    if is_merge(helper[prev_edge]) {
+                        diagonals.push((v, helper[prev_edge]));
+                    }
+                    status.retain(|&e| e != prev_edge);
+                    status.push(v);
+                    helper[v] = v;
+                } else {
+                    let e = edge_left_of(points, &status, v)?;
+                    if is_merge(helper[e]) {
+                        diagonals.push((v, helper[e]));
+                    }
+                    helper[e] = v;
+                }
+            }
+        }
+    }
+    Some(diagonals)
+}
+
+pub(crate) fn monotone_triangulation_diagonals(
+    points: &[&Point],
+    piece: &[usize]
+) -> Vec<(usize, usize)> {
+    // Triangulates a y-monotone piece (given as CCW indices into the
+    // points) in linear time after sorting, by sweeping top to bottom
+    // and maintaining a stack of vertices that still need diagonals
+    let m = piece.len();
+    let mut diagonals = Vec::new();
+    if m <= 3 {
+        return diagonals;
+    }
+
+    // Local positions are sorted so the chains can be identified,
+    // following next from the top vertex walks down the left chain
+    let local = (0..m).collect::<Vec<_>>();
+    let piece_points = piece.iter().map(|&i| points[i]).collect::<Vec<_>>();
+    let order = sweep_order(&piece_points, &local);
+    let (top, bottom) = (order[0], order[m - 1]);
+    let mut is_left = vec![false; m];
+    let mut k = (top + 1) % m;
+    while k != bottom {
+        is_left[k] = true;
+        k = (k + 1) % m;
+    }
+
+    let mut stack = vec![order[0], order[1]];
+    for j in 2..(m - 1) {
+        let u = order[j];
+        let stack_top = *stack.last().unwrap();
+        if is_left[u] != is_left[stack_top] {
+            for &s in stack.iter().skip(1) {
+                diagonals.push((piece[u], piece[s]));
+            }
+            stack = vec![order[j - 1], u];
+        } else {
+            let mut last = stack.pop().unwrap();
+            while let Some(&s) = stack.last() {
+                let (pu, pl, ps) = (piece_points[u], piece_points[last], piece_points[s]);
+                let inside = match is_left[u] {
+                    true  => Triangle::new(ps, pl, pu).area() > 0.0,
+                    false => Triangle::new(pu, pl, ps).area() > 0.0,
+                };
+                if !inside {
+                    break;
+                }
+                diagonals.push((piece[u], piece[s]));
+                last = stack.pop().unwrap();
+            }
+            stack.push(last);
+            stack.push(u);
+        }
+    }
+
+    // Bottom vertex connects to everything left on the stack except
+    // the first and last, which are already its neighbors
+    let u = order[m - 1];
+    for &s in stack.iter().skip(1).take(stack.len() - 2) {
+        diagonals.push((piece[u], piece[s]));
+    }
+    diagonals
+}
+
+pub(crate) fn trace_faces(points: &[&Point], diagonals: &[(usize, usize)]) -> Vec<Vec<usize>> {
+    // Splits the polygon into the faces formed by its boundary and the
+    // given (non-crossing) diagonals. Each face is returned as a list
+    // of indices in CCW order. Faces are traced keeping the face on the
+    // left of each directed edge, so at each vertex the next edge is
+    // the one immediately clockwise from the edge arrived on.
+    let n = points.len();
+    let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut add_edge = |a: usize, b: usize| {
+        if !neighbors[a].contains(&b) {
+            neighbors[a].push(b);
+            neighbors[b].push(a);
+        }
+    };
+    for i in 0..n {
+        add_edge(i, (i + 1) % n);
+    }
+    for &(a, b) in diagonals {
+        add_edge(a, b);
+    }
+
+    let angle = |from: usize, to: usize| {
+        (points[to].y - points[from].y).atan2(points[to].x - points[from].x)
+    };
+    for (i, nbrs) in neighbors.iter_mut().enumerate() {
+        nbrs.sort_by(|&a, &b| angle(i, a).total_cmp(&angle(i, b)));
+    }
+
+    // Reversed boundary edges would trace the exterior, so only the
+    // CCW boundary edges and diagonals in both directions are used
+    let mut starts = (0..n).map(|i| (i, (i + 1) % n)).collect::<Vec<_>>();
+    starts.extend(diagonals.iter().map(|&(a, b)| (a, b)));
+    starts.extend(diagonals.iter().map(|&(a, b)| (b, a)));
+
+    let mut visited = HashSet::new();
+    let mut faces = Vec::new();
+    for start in starts {
+        if !visited.insert(start) {
+            continue;
+        }
+        let mut face = vec![start.0];
+        let (mut a, mut b) = start;
+        loop {
+            let nbrs = &neighbors[b];
+            let k = nbrs.iter().position(|&x| x == a).unwrap();
+            let c = nbrs[(k + nbrs.len() - 1) % nbrs.len()];
+            if (b, c) == start {
+                break;
+            }
+            face.push(b);
+            visited.insert((b, c));
+            (a, b) = (b, c);
+        }
+        faces.push(face);
+    }
+    faces
+}
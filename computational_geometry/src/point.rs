@@ -1,29 +1,49 @@
+use std::cmp::Ordering;
+use std::ops::Add;
+
 use serde::{Deserialize, Serialize};
 
 use crate::{
     line_segment::LineSegment,
-    triangle::Triangle,
+    predicates,
+    scalar::Scalar,
+    vector::Vector2,
 };
 
 
+// Which side of a directed line segment a point lies on
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Turn {
+    Left,
+    Right,
+    Collinear,
+}
+
+
+// Coordinates are f64 unless another type is given, e.g. Point<f32>
+// to halve the memory used. Floating point coordinates implement
+// `Scalar`, Point<i64> only has the exact integer predicates.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
-pub struct Point {
-    pub x: f64,
-    pub y: f64,
+pub struct Point<T = f64> {
+    pub x: T,
+    pub y: T,
 }
 
 
-impl Point {
-    pub fn new(x: f64, y: f64) -> Self {
+impl<T> Point<T> {
+    pub fn new(x: T, y: T) -> Self {
         Point { x, y }
     }
+}
 
-    pub fn between(&self, a: &Point, b: &Point) -> bool {
-        if !Triangle::new(a, b, self).has_collinear_points() {
+impl<T: Scalar> Point<T> {
+    pub fn between(&self, a: &Point<T>, b: &Point<T>) -> bool {
+        let ab = LineSegment::new(a, b);
+        if self.orientation(&ab) != Turn::Collinear {
             return false;
         }
 
-        let (e1, e2, check) = match LineSegment::new(a, b).is_vertical() {
+        let (e1, e2, check) = match ab.is_vertical() {
             true  => (a.y, b.y, self.y),
             false => (a.x, b.x, self.x),
         };
@@ -31,38 +51,135 @@ impl Point {
         (e1..e2).contains(&check) || (e2..e1).contains(&check)
     }
 
-    pub fn left(&self, ab: &LineSegment) -> bool {
-        Triangle::new(ab.p1, ab.p2, self).area() > 0.0
+    pub fn orientation(&self, ab: &LineSegment<T>) -> Turn {
+        // The exact predicate is opt-in since it is slower on the
+        // nearly-degenerate inputs where the two can disagree
+        if cfg!(feature = "exact-orientation") {
+            return self.orientation_exact(ab);
+        }
+        let area = Vector2::between(ab.p1, ab.p2).cross(&Vector2::between(ab.p1, self));
+        if area > T::zero() {
+            Turn::Left
+        } else if area < T::zero() {
+            Turn::Right
+        } else {
+            Turn::Collinear
+        }
+    }
+
+    pub fn orientation_exact(&self, ab: &LineSegment<T>) -> Turn {
+        // Converting to f64 is exact so this is exact for any scalar
+        predicates::orientation(&ab.p1.to_f64(), &ab.p2.to_f64(), &self.to_f64())
+    }
+
+    pub fn left(&self, ab: &LineSegment<T>) -> bool {
+        self.orientation(ab) == Turn::Left
+    }
+
+    pub fn left_on(&self, ab: &LineSegment<T>) -> bool {
+        self.orientation(ab) != Turn::Right
+    }
+
+    pub fn distance_to(&self, other: &Point<T>) -> T {
+        self.distance_squared_to(other).sqrt()
+    }
+
+    pub fn distance_squared_to(&self, other: &Point<T>) -> T {
+        // Avoids the sqrt for callers that only need to compare 
+        // relative magnitudes of distances
+        (self - other).norm_squared()
+    }
+
+    #[deprecated(note = "subtract points to get a `Vector2` and use `Vector2::dot`")]
+    pub fn dot(&self, other: &Point<T>) -> T {
+        Vector2::new(self.x, self.y).dot(&Vector2::new(other.x, other.y))
+    }
+
+    #[deprecated(note = "subtract points to get a `Vector2` and use `Vector2::cross`")]
+    pub fn cross(&self, other: &Point<T>) -> T {
+        // Z component of the 3D cross product, positive when other is
+        // CCW from self when both are treated as vectors from the origin
+        Vector2::new(self.x, self.y).cross(&Vector2::new(other.x, other.y))
     }
 
-    pub fn left_on(&self, ab: &LineSegment) -> bool {
-        Triangle::new(ab.p1, ab.p2, self).area() >= 0.0
+    pub fn to_f64(&self) -> Point {
+        Point::new(self.x.to_f64(), self.y.to_f64())
     }
 
-    pub fn translate(&mut self, x: f64, y: f64) {
+    pub fn translate(&mut self, x: T, y: T) {
         self.x += x;
         self.y += y;
     }
 
-    pub fn rotate_about_origin(&mut self, radians: f64) {
-        let origin = Point::new(0.0, 0.0);
+    pub fn rotate_about_origin(&mut self, radians: T) {
+        let origin = Point::new(T::zero(), T::zero());
         self.rotate_about_point(radians, &origin);
     }
 
-    pub fn rotate_about_point(&mut self, radians: f64, point: &Point) {
+    pub fn rotate_about_point(&mut self, radians: T, point: &Point<T>) {
         let cos_theta = radians.cos();
         let sin_theta = radians.sin();
-        let x_diff = self.x - point.x;
-        let y_diff = self.y - point.y;
-        let x1 = x_diff * cos_theta - y_diff * sin_theta + point.x;
-        let y1 = x_diff * sin_theta + y_diff * cos_theta + point.y;
-        self.x = x1;
-        self.y = y1;
+        let diff = &*self - point;
+        let rotated = Vector2::new(
+            diff.x * cos_theta - diff.y * sin_theta,
+            diff.x * sin_theta + diff.y * cos_theta,
+        );
+        *self = point + rotated;
+    }
+
+    pub fn scale_about_point(&mut self, factor: T, point: &Point<T>) {
+        self.x = point.x + factor * (self.x - point.x);
+        self.y = point.y + factor * (self.y - point.y);
     }
 
     pub fn round(&mut self) {
-        self.x = f64::round(self.x);
-        self.y = f64::round(self.y);
+        self.x = self.x.round();
+        self.y = self.y.round();
+    }
+}
+
+
+// Exact predicates for integer coordinates. Determinants are evaluated
+// in i128 with checked arithmetic, None if they overflow which can only
+// happen for coordinates beyond +/-2^62.
+impl Point<i64> {
+    pub fn orientation(&self, ab: &LineSegment<i64>) -> Option<Turn> {
+        let (a, b) = (ab.p1, ab.p2);
+        let left = (b.x as i128 - a.x as i128).checked_mul(self.y as i128 - a.y as i128)?;
+        let right = (self.x as i128 - a.x as i128).checked_mul(b.y as i128 - a.y as i128)?;
+        let turn = match left.checked_sub(right)?.cmp(&0) {
+            Ordering::Greater => Turn::Left,
+            Ordering::Less => Turn::Right,
+            Ordering::Equal => Turn::Collinear,
+        };
+        Some(turn)
+    }
+
+    pub fn left(&self, ab: &LineSegment<i64>) -> Option<bool> {
+        self.orientation(ab).map(|turn| turn == Turn::Left)
+    }
+
+    pub fn left_on(&self, ab: &LineSegment<i64>) -> Option<bool> {
+        self.orientation(ab).map(|turn| turn != Turn::Right)
+    }
+}
+
+
+// Component-wise, treating points as vectors from the origin. Kept for
+// existing callers, new code should add a `Vector2` to a point instead.
+impl<T: Scalar> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Point<T>) -> Point<T> {
+        &self + &other
+    }
+}
+
+impl<T: Scalar> Add for &Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: &Point<T>) -> Point<T> {
+        Point::new(self.x + other.x, self.y + other.y)
     }
 }
 
@@ -123,6 +240,49 @@ mod tests {
         assert!(!p2.between(&p1, &p0));
     }
 
+    #[rstest]
+    #[case(Point::new(1.0, 1.0), Turn::Left)]
+    #[case(Point::new(1.0, -1.0), Turn::Right)]
+    #[case(Point::new(1.0, 0.0), Turn::Collinear)]
+    #[case(Point::new(5.0, 0.0), Turn::Collinear)]
+    #[case(Point::new(0.0, 0.0), Turn::Collinear)]
+    #[case(Point::new(2.0, 0.0), Turn::Collinear)]
+    fn test_orientation(#[case] p: Point, #[case] expected: Turn) {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(2.0, 0.0);
+        let ab = LineSegment::new(&a, &b);
+        assert_eq!(p.orientation(&ab), expected);
+        assert_eq!(p.left(&ab), expected == Turn::Left);
+        assert_eq!(p.left_on(&ab), expected != Turn::Right);
+
+        // Reversing the segment swaps left and right
+        let ba = ab.reverse();
+        let reversed = match expected {
+            Turn::Left => Turn::Right,
+            Turn::Right => Turn::Left,
+            Turn::Collinear => Turn::Collinear,
+        };
+        assert_eq!(p.orientation(&ba), reversed);
+        assert_eq!(p.orientation_exact(&ab), expected);
+        assert_eq!(p.orientation_exact(&ba), reversed);
+    }
+
+    #[rstest]
+    #[case(Point::new(0.0, 0.0), Point::new(3.0, 0.0), 3.0)]
+    #[case(Point::new(0.0, 0.0), Point::new(-3.0, 0.0), 3.0)]
+    #[case(Point::new(1.0, 2.0), Point::new(1.0, 7.0), 5.0)]
+    #[case(Point::new(1.0, 2.0), Point::new(1.0, -3.0), 5.0)]
+    #[case(Point::new(0.0, 0.0), Point::new(3.0, 4.0), 5.0)]
+    #[case(Point::new(-1.0, -1.0), Point::new(2.0, 3.0), 5.0)]
+    #[case(Point::new(0.0, 0.0), Point::new(1.0, 1.0), SQRT_2)]
+    #[case(Point::new(2.5, -1.5), Point::new(2.5, -1.5), 0.0)]
+    fn test_distance_to(#[case] a: Point, #[case] b: Point, #[case] distance: f64) {
+        assert_approx_eq!(a.distance_to(&b), distance, F64_ASSERT_PRECISION);
+        assert_approx_eq!(b.distance_to(&a), distance, F64_ASSERT_PRECISION);
+        assert_approx_eq!(a.distance_squared_to(&b), distance * distance, F64_ASSERT_PRECISION);
+        assert_approx_eq!(b.distance_squared_to(&a), distance * distance, F64_ASSERT_PRECISION);
+    }
+
     #[template]
     #[rstest]
     #[case(0.0, 1.0, 0.0)]
@@ -180,5 +340,142 @@ mod tests {
         assert_approx_eq!(p1.y, p2.y, F64_ASSERT_PRECISION);
     }
 
+    #[rstest]
+    #[case(2.0, Point::new(0.0, 0.0), Point::new(6.0, 8.0))]
+    #[case(2.0, Point::new(1.0, 1.0), Point::new(5.0, 7.0))]
+    #[case(0.5, Point::new(1.0, 2.0), Point::new(2.0, 3.0))]
+    #[case(-1.0, Point::new(0.0, 0.0), Point::new(-3.0, -4.0))]
+    #[case(0.0, Point::new(1.0, 1.0), Point::new(1.0, 1.0))]
+    fn test_scale_about_point(#[case] factor: f64, #[case] about: Point, #[case] expected: Point) {
+        let mut p = Point::new(3.0, 4.0);
+        p.scale_about_point(factor, &about);
+        assert_approx_eq!(p.x, expected.x, F64_ASSERT_PRECISION);
+        assert_approx_eq!(p.y, expected.y, F64_ASSERT_PRECISION);
+    }
+
+    #[test]
+    fn test_sub() {
+        // Difference of positions is the displacement between them
+        let a = Point::new(1.0, 2.0);
+        let b = Point::new(4.0, -1.5);
+        assert_eq!(&a - &b, Vector2::new(-3.0, 3.5));
+        assert_eq!(&b + (&a - &b), a);
+        assert_eq!(a.clone() - a.clone(), Vector2::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_add() {
+        let a = Point::new(1.0, 2.0);
+        let b = Point::new(4.0, -1.5);
+        assert_eq!(&a + &b, Point::new(5.0, 0.5));
+        assert_eq!(a.clone() + b.clone(), b.clone() + a.clone());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_dot_cross() {
+        // Same as the Vector2 versions for position vectors
+        let a = Point::new(1.0, 2.0);
+        let b = Point::new(3.0, 4.0);
+        assert_eq!(a.dot(&b), 11.0);
+        assert_eq!(a.dot(&Point::new(-2.0, 1.0)), 0.0);
+        assert_eq!(a.cross(&b), -2.0);
+        assert_eq!(a.cross(&b), Vector2::from(a.clone()).cross(&Vector2::from(b.clone())));
+    }
+
+    #[rstest]
+    #[case(Point::new(0.0, 1.0), 2.0)]
+    #[case(Point::new(-1.0, 1.0), 3.0)]
+    #[case(Point::new(0.0, -1.0), -2.0)]
+    #[case(Point::new(1.0, -1.0), -3.0)]
+    #[case(Point::new(4.0, 2.0), 0.0)]
+    #[case(Point::new(-2.0, -1.0), 0.0)]
+    fn test_orientation_matches_cross(#[case] b: Point, #[case] expected: f64) {
+        // Left when b is CCW from a about the origin, right when CW
+        let a = Point::new(2.0, 1.0);
+        let o = Point::new(0.0, 0.0);
+        assert_eq!((&a - &o).cross(&(&b - &o)), expected);
+        let orientation = b.orientation(&LineSegment::new(&o, &a));
+        match expected {
+            e if e > 0.0 => assert_eq!(orientation, Turn::Left),
+            e if e < 0.0 => assert_eq!(orientation, Turn::Right),
+            _ => assert_eq!(orientation, Turn::Collinear),
+        }
+    }
+
+    fn check_scalar<T: Scalar>() {
+        let s = T::from_f64;
+        let a = Point::<T>::new(s(0.0), s(0.0));
+        let b = Point::new(s(3.0), s(0.0));
+        let c = Point::new(s(3.0), s(4.0));
+        assert_eq!(a.distance_to(&c), s(5.0));
+        assert_eq!(a.distance_squared_to(&c), s(25.0));
+        assert_eq!((&b - &a).cross(&(&c - &a)), s(12.0));
+        assert_eq!(&c - &b, Vector2::new(s(0.0), s(4.0)));
+
+        let ab = LineSegment::new(&a, &b);
+        assert_eq!(c.orientation(&ab), Turn::Left);
+        assert_eq!(c.orientation_exact(&ab), Turn::Left);
+        assert!(Point::new(s(1.5), s(0.0)).between(&a, &b));
+
+        let mut p = b.clone();
+        p.rotate_about_point(s(FRAC_PI_2), &a);
+        assert!((p.x - s(0.0)).abs() < s(1e-6));
+        assert!((p.y - s(3.0)).abs() < s(1e-6));
+        p.round();
+        assert_eq!(p, Point::new(s(0.0), s(3.0)));
+    }
+
+    #[test]
+    fn test_i64_orientation() {
+        let a = Point::new(0i64, 0);
+        let b = Point::new(2, 2);
+        let ab = LineSegment::new(&a, &b);
+        assert_eq!(Point::new(5, 5).orientation(&ab), Some(Turn::Collinear));
+        assert_eq!(Point::new(-3, -3).orientation(&ab), Some(Turn::Collinear));
+        assert_eq!(Point::new(0, 1).orientation(&ab), Some(Turn::Left));
+        assert_eq!(Point::new(1, 0).orientation(&ab), Some(Turn::Right));
+        assert_eq!(Point::new(5, 5).left_on(&ab), Some(true));
+        assert_eq!(Point::new(5, 5).left(&ab), Some(false));
+    }
+
+    #[test]
+    fn test_i64_orientation_beyond_f64_precision() {
+        // c is exactly 3b so all three are collinear, but neither x is
+        // representable as f64 and rounding them makes c look right of ab
+        let big = (1i64 << 53) + 1;
+        let a = Point::new(0i64, 0);
+        let b = Point::new(big, 1);
+        let c = Point::new(3 * big, 3);
+        assert_eq!(c.orientation(&LineSegment::new(&a, &b)), Some(Turn::Collinear));
+
+        let to_f64 = |p: &Point<i64>| Point::new(p.x as f64, p.y as f64);
+        let (af, bf, cf) = (to_f64(&a), to_f64(&b), to_f64(&c));
+        assert_eq!(cf.orientation(&LineSegment::new(&af, &bf)), Turn::Right);
+
+        // Largest supported coordinates, one unit off the line
+        let m = 1i64 << 62;
+        let a = Point::new(-m, -m);
+        let b = Point::new(m - 1, m - 1);
+        let ab = LineSegment::new(&a, &b);
+        assert_eq!(Point::new(m - 2, m - 2).orientation(&ab), Some(Turn::Collinear));
+        assert_eq!(Point::new(m - 2, m - 1).orientation(&ab), Some(Turn::Left));
+        assert_eq!(Point::new(-m, 1 - m).orientation(&ab), Some(Turn::Left));
+
+        // Beyond that the determinant doesn't fit in i128
+        let a = Point::new(i64::MIN, i64::MIN);
+        let b = Point::new(i64::MAX, i64::MIN);
+        let ab = LineSegment::new(&a, &b);
+        assert_eq!(Point::new(i64::MAX, i64::MAX).orientation(&ab), None);
+        assert_eq!(Point::new(i64::MAX, i64::MAX).left(&ab), None);
+    }
+
+    #[test]
+    fn test_scalar_types() {
+        check_scalar::<f32>();
+        check_scalar::<f64>();
+        assert_eq!(Point::new(0.5f32, 2.0).to_f64(), Point::new(0.5, 2.0));
+    }
+
     // TODO need tests for rotation about arbitrary point
 }
\ No newline at end of file
@@ -0,0 +1,196 @@
+use crate::{point::Point, polygon::Polygon};
+
+// Locates which trapezoid of `Polygon::trapezoidalize` a query point is
+// in. The plane is cut into horizontal slabs at every trapezoid's top
+// and bottom, within a slab the trapezoids crossing it are disjoint and
+// ordered left to right, so a query is one binary search for the slab
+// and one for the trapezoid within it, O(log n). Slabs store every
+// trapezoid crossing them so the structure is O(n^2) in the worst case,
+// though typically much smaller.
+//
+// Points on the boundary of the polygon are located like interior
+// points, points on the boundary between two trapezoids are given
+// either one of them.
+
+
+struct Trapezoid {
+    bottom: f64,
+    top: f64,
+    // Left and right sides from bottom to top, the same point for both
+    // ends of a side where the trapezoid degenerates to a triangle
+    left: (Point, Point),
+    right: (Point, Point),
+}
+
+impl Trapezoid {
+    fn new(polygon: &Polygon) -> Self {
+        let points = polygon.to_points();
+        let bottom = points.iter().map(|p| p.y).fold(f64::INFINITY, f64::min);
+        let top = points.iter().map(|p| p.y).fold(f64::NEG_INFINITY, f64::max);
+        let side = |y: f64, left: bool| {
+            let mut at_y = points.iter().filter(|p| p.y == y);
+            let first = at_y.next().unwrap().clone();
+            at_y.fold(first, |best, p| match (p.x < best.x) == left {
+                true  => p.clone(),
+                false => best,
+            })
+        };
+        Trapezoid {
+            bottom,
+            top,
+            left: (side(bottom, true), side(top, true)),
+            right: (side(bottom, false), side(top, false)),
+        }
+    }
+
+    fn x_at(side: &(Point, Point), y: f64) -> f64 {
+        let (p1, p2) = side;
+        match p1.y == p2.y {
+            true  => p1.x,
+            false => p1.x + (y - p1.y) * (p2.x - p1.x) / (p2.y - p1.y),
+        }
+    }
+
+    fn contains(&self, p: &Point) -> bool {
+        self.bottom <= p.y && p.y <= self.top
+            && Self::x_at(&self.left, p.y) <= p.x
+            && p.x <= Self::x_at(&self.right, p.y)
+    }
+}
+
+
+pub struct PointLocator {
+    trapezoids: Vec<Polygon>,
+    shapes: Vec<Trapezoid>,
+    levels: Vec<f64>,
+    // Trapezoids crossing the slab between each pair of consecutive
+    // levels, ordered left to right
+    slabs: Vec<Vec<usize>>,
+}
+
+impl PointLocator {
+    pub fn new(polygon: &Polygon) -> Self {
+        let trapezoids = polygon.trapezoidalize();
+        let shapes = trapezoids.iter().map(Trapezoid::new).collect::<Vec<_>>();
+        let mut levels = shapes.iter()
+            .flat_map(|t| [t.bottom, t.top])
+            .collect::<Vec<_>>();
+        levels.sort_by(f64::total_cmp);
+        levels.dedup();
+
+        let slabs = levels.windows(2)
+            .map(|w| {
+                let y_mid = 0.5 * (w[0] + w[1]);
+                let mut slab = (0..shapes.len())
+                    .filter(|&i| shapes[i].bottom <= w[0] && shapes[i].top >= w[1])
+                    .collect::<Vec<_>>();
+                slab.sort_by(|&a, &b| {
+                    Trapezoid::x_at(&shapes[a].left, y_mid)
+                        .total_cmp(&Trapezoid::x_at(&shapes[b].left, y_mid))
+                });
+                slab
+            })
+            .collect();
+        PointLocator { trapezoids, shapes, levels, slabs }
+    }
+
+    pub fn trapezoids(&self) -> &[Polygon] {
+        &self.trapezoids
+    }
+
+    pub fn locate(&self, p: &Point) -> Option<usize> {
+        // Index into `trapezoids` of the one containing the point, None
+        // if the point is outside the polygon
+        let i = self.levels.partition_point(|&y| y < p.y);
+        if i == self.levels.len() || (i == 0 && self.levels[0] != p.y) {
+            return None;
+        }
+        // Points exactly on a level could be in the slab either side of
+        // it, e.g. on a horizontal edge only bounding the slab above
+        let candidates = match self.levels[i] == p.y {
+            true  => vec![i.checked_sub(1), Some(i)],
+            false => vec![Some(i - 1)],
+        };
+        candidates.into_iter()
+            .flatten()
+            .filter(|&s| s < self.slabs.len())
+            .find_map(|s| self.locate_in_slab(s, p))
+    }
+
+    fn locate_in_slab(&self, slab: usize, p: &Point) -> Option<usize> {
+        // First trapezoid whose right side isn't left of the point is the
+        // only one that can contain it
+        let slab = &self.slabs[slab];
+        let k = slab.partition_point(|&t| Trapezoid::x_at(&self.shapes[t].right, p.y) < p.x);
+        slab.get(k)
+            .copied()
+            .filter(|&t| self.shapes[t].contains(p))
+    }
+}
+
+
+impl Polygon {
+    pub fn build_point_locator(&self) -> PointLocator {
+        PointLocator::new(self)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Geometry;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use rstest::rstest;
+
+    fn comb() -> Polygon {
+        Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(6.0, 0.0),
+            Point::new(6.0, 3.0),
+            Point::new(5.0, 1.0),
+            Point::new(4.0, 3.0),
+            Point::new(3.0, 1.0),
+            Point::new(2.0, 3.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 3.0),
+        ])
+    }
+
+    #[test]
+    fn test_locate_comb() {
+        let polygon = comb();
+        let locator = polygon.build_point_locator();
+        let i = locator.locate(&Point::new(3.0, 0.5)).unwrap();
+        assert!(locator.trapezoids()[i].contains_point(&Point::new(3.0, 0.5)));
+        // Between the teeth and outside the bounding box
+        assert_eq!(locator.locate(&Point::new(3.0, 2.0)), None);
+        assert_eq!(locator.locate(&Point::new(3.0, -1.0)), None);
+        assert_eq!(locator.locate(&Point::new(3.0, 4.0)), None);
+        // Boundary, including the bottom edge and tooth tips
+        for p in [Point::new(3.0, 0.0), Point::new(0.0, 3.0), Point::new(6.0, 3.0), Point::new(3.0, 1.0)] {
+            let i = locator.locate(&p).unwrap();
+            assert!(locator.trapezoids()[i].contains_point(&p), "{p:?}");
+        }
+    }
+
+    #[rstest]
+    fn test_matches_brute_force(#[values(0, 1, 2)] seed: u64) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        for polygon in [comb(), Polygon::random_simple(30, seed)] {
+            let locator = polygon.build_point_locator();
+            let bbox = polygon.bounding_box();
+            for _ in 0..500 {
+                let p = Point::new(
+                    rng.gen_range(bbox.min_x - 0.5..bbox.max_x + 0.5),
+                    rng.gen_range(bbox.min_y - 0.5..bbox.max_y + 0.5),
+                );
+                let expected = locator.trapezoids()
+                    .iter()
+                    .position(|t| t.contains_point(&p));
+                assert_eq!(locator.locate(&p), expected, "{p:?}");
+                assert_eq!(expected.is_some(), polygon.contains_point(&p));
+            }
+        }
+    }
+}
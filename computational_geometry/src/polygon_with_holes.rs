@@ -0,0 +1,178 @@
+use std::fs;
+use std::path::Path;
+
+use crate::{
+    point::Point,
+    polygon::{Polygon, PolygonError},
+};
+
+// A polygon with an outer boundary and any number of holes, each of
+// which is represented as its own simple polygon. The outer boundary
+// is kept CCW and the holes CW so that the interior is always to the
+// left of every boundary edge. Holes are assumed to lie inside the
+// outer boundary and not overlap each other, this is not validated.
+//
+// Only area, containment and loading currently account for holes.
+// Everything else (triangulation, convex decomposition, calipers,
+// etc.) is only available on the individual rings through `outer` and
+// `holes`, and so ignores the holes entirely.
+
+
+#[derive(Debug, PartialEq)]
+pub struct PolygonWithHoles {
+    outer: Polygon,
+    holes: Vec<Polygon>,
+}
+
+impl PolygonWithHoles {
+    pub fn new(mut outer: Polygon, mut holes: Vec<Polygon>) -> PolygonWithHoles {
+        outer.ensure_ccw();
+        for hole in holes.iter_mut() {
+            hole.ensure_cw();
+        }
+        PolygonWithHoles { outer, holes }
+    }
+
+    pub fn from_ipa_json<P: AsRef<Path>>(path: P) -> Result<PolygonWithHoles, PolygonError> {
+        // Same schema as `Polygon::from_ipa_json`, except the remaining
+        // rings after the outer boundary are kept as holes
+        let polygon_str: String = fs::read_to_string(path)?;
+        let rings: Vec<Vec<[f64; 2]>> = serde_json::from_str(&polygon_str)?;
+        let mut rings = rings.into_iter()
+            .map(|ring| {
+                let points = ring.into_iter()
+                    .map(|[x, y]| Point::new(x, y))
+                    .collect();
+                Polygon::try_new(points)
+            });
+        let outer = rings.next().ok_or(PolygonError::MissingOuterBoundary)??;
+        let holes = rings.collect::<Result<Vec<_>, _>>()?;
+        Ok(PolygonWithHoles::new(outer, holes))
+    }
+
+    pub fn outer(&self) -> &Polygon {
+        &self.outer
+    }
+
+    pub fn holes(&self) -> &[Polygon] {
+        &self.holes
+    }
+
+    pub fn num_holes(&self) -> usize {
+        self.holes.len()
+    }
+
+    pub fn area(&self) -> f64 {
+        let hole_area = self.holes.iter()
+            .map(|h| h.area())
+            .sum::<f64>();
+        self.outer.area() - hole_area
+    }
+
+    pub fn contains(&self, p: &Point) -> bool {
+        // Holes are CW so a point inside one has winding number -1 with
+        // respect to it, cancelling out the +1 from the outer boundary.
+        // Boundary points follow the same rules as `winding_number`.
+        let winding_number = self.outer.winding_number(p) + self.holes.iter()
+            .map(|h| h.winding_number(p))
+            .sum::<i32>();
+        winding_number != 0
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+    use tempfile::NamedTempFile;
+    use crate::{polygon::Orientation, test_util::square, F64_ASSERT_PRECISION};
+
+    fn square_with_two_holes() -> PolygonWithHoles {
+        PolygonWithHoles::new(
+            square(0.0, 0.0, 10.0),
+            vec![square(1.0, 1.0, 2.0), square(5.0, 5.0, 4.0)],
+        )
+    }
+
+    #[test]
+    fn test_orientation() {
+        let polygon = square_with_two_holes();
+        assert_eq!(polygon.outer().orientation(), Orientation::CounterClockwise);
+        for hole in polygon.holes() {
+            assert_eq!(hole.orientation(), Orientation::Clockwise);
+        }
+    }
+
+    #[test]
+    fn test_area() {
+        let polygon = square_with_two_holes();
+        assert_approx_eq!(polygon.area(), 100.0 - 4.0 - 16.0, F64_ASSERT_PRECISION);
+    }
+
+    #[test]
+    fn test_area_no_holes() {
+        let polygon = PolygonWithHoles::new(square(0.0, 0.0, 10.0), Vec::new());
+        assert_approx_eq!(polygon.area(), polygon.outer().area(), F64_ASSERT_PRECISION);
+    }
+
+    #[test]
+    fn test_contains() {
+        let polygon = square_with_two_holes();
+        assert!( polygon.contains(&Point::new(4.0, 4.0)));
+        assert!( polygon.contains(&Point::new(0.5, 9.5)));
+        assert!( polygon.contains(&Point::new(4.0, 2.0)));
+        assert!(!polygon.contains(&Point::new(2.0, 2.0)));
+        assert!(!polygon.contains(&Point::new(7.0, 7.0)));
+        assert!(!polygon.contains(&Point::new(11.0, 5.0)));
+        assert!(!polygon.contains(&Point::new(-1.0, -1.0)));
+    }
+
+    #[test]
+    fn test_from_ipa_json() {
+        let filename = NamedTempFile::new()
+            .unwrap()
+            .into_temp_path();
+        // Hole is given CCW here and should be flipped to CW
+        let ipa_str = "[
+            [[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]],
+            [[1.0, 1.0], [2.0, 1.0], [2.0, 2.0], [1.0, 2.0]]
+        ]";
+        fs::write(&filename, ipa_str).unwrap();
+        let polygon = PolygonWithHoles::from_ipa_json(&filename).unwrap();
+        assert_eq!(polygon.num_holes(), 1);
+        assert_eq!(polygon.holes()[0].orientation(), Orientation::Clockwise);
+        assert_approx_eq!(polygon.area(), 15.0, F64_ASSERT_PRECISION);
+        assert!(!polygon.contains(&Point::new(1.5, 1.5)));
+        assert!( polygon.contains(&Point::new(3.0, 3.0)));
+    }
+
+    #[test]
+    fn test_from_ipa_json_empty() {
+        let filename = NamedTempFile::new()
+            .unwrap()
+            .into_temp_path();
+        fs::write(&filename, "[]").unwrap();
+        let result = PolygonWithHoles::from_ipa_json(&filename);
+        assert!(matches!(result, Err(PolygonError::MissingOuterBoundary)));
+    }
+
+    #[test]
+    fn test_from_ipa_json_invalid_ring() {
+        let filename = NamedTempFile::new()
+            .unwrap()
+            .into_temp_path();
+        // Self-intersecting hole
+        let ipa_str = "[
+            [[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]],
+            [[1.0, 1.0], [2.0, 2.0], [2.0, 1.0], [1.0, 2.0]]
+        ]";
+        fs::write(&filename, ipa_str).unwrap();
+        let result = PolygonWithHoles::from_ipa_json(&filename);
+        assert!(matches!(result, Err(PolygonError::NotSimple)));
+
+        fs::write(&filename, "[[[0.0, 0.0], [4.0, 0.0]]]").unwrap();
+        let result = PolygonWithHoles::from_ipa_json(&filename);
+        assert!(matches!(result, Err(PolygonError::TooFewVertices(2))));
+    }
+}
@@ -0,0 +1,125 @@
+use crate::point::{Point, Turn};
+
+// Exact geometric predicates over f64 coordinates using the adaptive
+// approach from Shewchuk "Adaptive Precision Floating-Point Arithmetic
+// and Fast Robust Geometric Predicates". A plain f64 evaluation is used
+// when its error bound guarantees the sign is right, otherwise the
+// determinant is evaluated exactly as a floating point expansion (a sum
+// of non-overlapping f64 components ordered by increasing magnitude).
+
+
+const EPSILON: f64 = f64::EPSILON / 2.0;
+const ORIENTATION_ERROR_BOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+
+
+pub(crate) fn orientation(a: &Point, b: &Point, c: &Point) -> Turn {
+    let det_left = (b.x - a.x) * (c.y - a.y);
+    let det_right = (c.x - a.x) * (b.y - a.y);
+    let det = det_left - det_right;
+    let error_bound = ORIENTATION_ERROR_BOUND * (det_left.abs() + det_right.abs());
+    if det.abs() > error_bound {
+        return sign(det);
+    }
+    sign(exact_orientation_det(a, b, c))
+}
+
+fn sign(det: f64) -> Turn {
+    if det > 0.0 {
+        Turn::Left
+    } else if det < 0.0 {
+        Turn::Right
+    } else {
+        Turn::Collinear
+    }
+}
+
+fn exact_orientation_det(a: &Point, b: &Point, c: &Point) -> f64 {
+    // Differences of coordinates are not exact in f64, so the
+    // determinant is expanded into products of the raw coordinates,
+    // each of which is exactly the sum of two f64 values
+    let terms = [
+        ( b.x, c.y),
+        (-b.x, a.y),
+        (-a.x, c.y),
+        (-c.x, b.y),
+        ( c.x, a.y),
+        ( a.x, b.y),
+    ];
+    let mut expansion = Vec::with_capacity(2 * terms.len());
+    for (x, y) in terms {
+        let (product, error) = two_product(x, y);
+        grow_expansion(&mut expansion, error);
+        grow_expansion(&mut expansion, product);
+    }
+    // Largest magnitude non-zero component determines the sign
+    expansion.iter()
+        .rev()
+        .find(|e| **e != 0.0)
+        .copied()
+        .unwrap_or(0.0)
+}
+
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let b_virtual = x - a;
+    let a_virtual = x - b_virtual;
+    (x, (a - a_virtual) + (b - b_virtual))
+}
+
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let x = a * b;
+    (x, a.mul_add(b, -x))
+}
+
+fn grow_expansion(expansion: &mut Vec<f64>, b: f64) {
+    let mut q = b;
+    for e in expansion.iter_mut() {
+        let (sum, error) = two_sum(q, *e);
+        *e = error;
+        q = sum;
+    }
+    expansion.push(q);
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use rstest::rstest;
+
+    #[test]
+    fn test_nearly_collinear() {
+        // The naive determinant rounds to the wrong sign for these
+        // points, the exact result was verified with rational arithmetic
+        let a = Point::new(0.5000000000000046, 0.5000000000000057);
+        let b = Point::new(12.0, 12.0);
+        let c = Point::new(24.0, 24.0);
+        let naive = (b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y);
+        assert!(naive < 0.0);
+        assert_eq!(orientation(&a, &b, &c), Turn::Left);
+        assert_eq!(orientation(&b, &a, &c), Turn::Right);
+    }
+
+    #[test]
+    fn test_collinear() {
+        let a = Point::new(0.1, 0.1);
+        let b = Point::new(12.0, 12.0);
+        let c = Point::new(24.0, 24.0);
+        assert_eq!(orientation(&a, &b, &c), Turn::Collinear);
+        assert_eq!(orientation(&a, &a, &c), Turn::Collinear);
+    }
+
+    #[rstest]
+    fn test_matches_naive_random(#[values(0, 1, 2)] seed: u64) {
+        // Random points are far from degenerate so the naive sign is right
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut random = || Point::new(rng.gen_range(-100.0..100.0), rng.gen_range(-100.0..100.0));
+        for _ in 0..1000 {
+            let (a, b, c) = (random(), random(), random());
+            let naive = (b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y);
+            assert_eq!(orientation(&a, &b, &c), sign(naive));
+            assert_eq!(sign(exact_orientation_det(&a, &b, &c)), sign(naive));
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use std::{
+    cmp::Ordering,
+    fmt::Debug,
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
+};
+
+// Floating point types usable as coordinates of points, vectors,
+// segments, triangles, vertices and the core of polygons. Everything
+// that can be computed in the coordinate type is, the exact predicates
+// are done in f64 which every implementor converts to without loss.
+// Polygon algorithms beyond construction, measurement and transforms
+// are f64 only.
+
+
+pub trait Scalar:
+    Copy
+    + Debug
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+{
+    fn zero() -> Self;
+    fn from_f64(v: f64) -> Self;
+    fn to_f64(self) -> f64;
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn round(self) -> Self;
+    fn abs(self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+
+macro_rules! impl_scalar {
+    ($t:ty) => {
+        impl Scalar for $t {
+            fn zero() -> Self {
+                0.0
+            }
+
+            fn from_f64(v: f64) -> Self {
+                v as $t
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn sqrt(self) -> Self {
+                <$t>::sqrt(self)
+            }
+
+            fn sin(self) -> Self {
+                <$t>::sin(self)
+            }
+
+            fn cos(self) -> Self {
+                <$t>::cos(self)
+            }
+
+            fn round(self) -> Self {
+                <$t>::round(self)
+            }
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn min(self, other: Self) -> Self {
+                <$t>::min(self, other)
+            }
+
+            fn max(self, other: Self) -> Self {
+                <$t>::max(self, other)
+            }
+
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                <$t>::total_cmp(self, other)
+            }
+        }
+    };
+}
+
+impl_scalar!(f32);
+impl_scalar!(f64);
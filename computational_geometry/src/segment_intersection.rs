@@ -0,0 +1,290 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use crate::{
+    line_segment::LineSegment,
+    point::Point,
+};
+
+
+const EVENT_TOLERANCE: f64 = 1e-9;
+
+
+// Event points are processed left to right (lexicographically by x
+// then y), so this gives the points a total order for the event queue
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct EventPoint(f64, f64);
+
+impl EventPoint {
+    fn new(p: &Point) -> Self {
+        // Adding zero maps -0.0 to 0.0, otherwise the total order would
+        // treat them as distinct event points
+        EventPoint(p.x + 0.0, p.y + 0.0)
+    }
+}
+
+impl Eq for EventPoint {}
+
+impl PartialOrd for EventPoint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for EventPoint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0).then(self.1.total_cmp(&other.1))
+    }
+}
+
+
+// Segments starting at, ending at, and known to pass through the point
+#[derive(Default)]
+struct Event {
+    left: Vec<usize>,
+    right: Vec<usize>,
+    interior: Vec<usize>,
+}
+
+
+pub fn segment_intersections(segments: &[LineSegment]) -> Vec<(usize, usize, Point)> {
+    // Bentley-Ottmann sweep reporting every intersecting pair of
+    // segments (by index, with i < j) along with their intersection
+    // point, which matches `LineSegment::intersection` for the pair.
+    // The sweep status holds the segments crossing the sweep line
+    // ordered bottom to top, and only segments that become adjacent in
+    // the status are tested for intersection. All segments through an
+    // event point are handled together, which covers shared endpoints,
+    // endpoints touching other segments and collinear overlap. Status
+    // lookups are binary searches, though updating the status is a
+    // linear shift since it is stored as a sorted Vec.
+    let ends = segments.iter()
+        .map(|s| match EventPoint::new(s.p1) <= EventPoint::new(s.p2) {
+            true  => (s.p1, s.p2),
+            false => (s.p2, s.p1),
+        })
+        .collect::<Vec<_>>();
+
+    let mut events: BTreeMap<EventPoint, Event> = BTreeMap::new();
+    for (i, (a, b)) in ends.iter().enumerate() {
+        events.entry(EventPoint::new(a)).or_default().left.push(i);
+        events.entry(EventPoint::new(b)).or_default().right.push(i);
+    }
+
+    let mut status: Vec<usize> = Vec::new();
+    let mut reported: BTreeMap<(usize, usize), Point> = BTreeMap::new();
+    let mut report = |i: usize, j: usize, p: &Point| {
+        reported.entry((i.min(j), i.max(j))).or_insert_with(|| p.clone());
+    };
+
+    while let Some((key, event)) = events.pop_first() {
+        let p = Point::new(key.0, key.1);
+        let y_at = |s: usize| {
+            // Vertical segments in the status always contain the event
+            let (a, b) = ends[s];
+            match a.x == b.x {
+                true  => p.y,
+                false => a.y + (p.x - a.x) * (b.y - a.y) / (b.x - a.x),
+            }
+        };
+        let contains = |s: usize| {
+            // Tolerance groups segments through (nearly) the same point,
+            // since intersections computed for different pairs of
+            // segments through a common point rarely agree exactly
+            event.interior.contains(&s)
+                || event.right.contains(&s)
+                || (y_at(s) - p.y).abs() <= EVENT_TOLERANCE * (1.0 + p.y.abs())
+        };
+
+        // Segments through the event point are contiguous in the status
+        let pos = status.partition_point(|&s| y_at(s) < p.y);
+        let (mut lo, mut hi) = (pos, pos);
+        while lo > 0 && contains(status[lo - 1]) {
+            lo -= 1;
+        }
+        while hi < status.len() && contains(status[hi]) {
+            hi += 1;
+        }
+        let through = status.drain(lo..hi).collect::<Vec<_>>();
+        if event.right.iter().any(|s| !through.contains(s)) {
+            // Only reachable through floating point error in the status
+            // ordering, but ending segments must always be removed
+            status.retain(|s| !event.right.contains(s));
+            lo = status.partition_point(|&s| y_at(s) < p.y);
+        }
+
+        let mut involved = through.iter()
+            .chain(event.left.iter())
+            .chain(event.right.iter())
+            .copied()
+            .collect::<Vec<_>>();
+        involved.sort();
+        involved.dedup();
+        for (k, &i) in involved.iter().enumerate() {
+            for &j in involved[k + 1..].iter() {
+                report(i, j, &p);
+            }
+        }
+
+        // Segments continuing past the event are re-inserted ordered by
+        // slope, which is their bottom to top order right of the event
+        let mut continuing = through.into_iter()
+            .chain(event.left.iter().copied())
+            .filter(|&s| EventPoint::new(ends[s].1) != key)
+            .collect::<Vec<_>>();
+        let slope = |s: usize| {
+            let (a, b) = ends[s];
+            (b.y - a.y).atan2(b.x - a.x)
+        };
+        continuing.sort_by(|&s, &t| slope(s).total_cmp(&slope(t)));
+        continuing.dedup();
+        let num_continuing = continuing.len();
+        status.splice(lo..lo, continuing);
+
+        let mut neighbors = Vec::new();
+        if num_continuing == 0 {
+            if lo > 0 && lo < status.len() {
+                neighbors.push((status[lo - 1], status[lo]));
+            }
+        } else {
+            let top = lo + num_continuing - 1;
+            if lo > 0 {
+                neighbors.push((status[lo - 1], status[lo]));
+            }
+            if top + 1 < status.len() {
+                neighbors.push((status[top], status[top + 1]));
+            }
+        }
+        for (s, t) in neighbors {
+            if let Some(q) = segments[s].intersection(&segments[t]) {
+                if EventPoint::new(&q) > key {
+                    events.entry(EventPoint::new(&q)).or_default().interior.extend([s, t]);
+                } else {
+                    report(s, t, &q);
+                }
+            }
+        }
+    }
+
+    reported.into_iter()
+        .map(|((i, j), p)| (i, j, p))
+        .collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::F64_ASSERT_PRECISION;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use rstest::rstest;
+
+    fn naive_intersections(segments: &[LineSegment]) -> Vec<(usize, usize, Point)> {
+        let mut intersections = Vec::new();
+        for i in 0..segments.len() {
+            for j in (i + 1)..segments.len() {
+                if let Some(p) = segments[i].intersection(&segments[j]) {
+                    intersections.push((i, j, p));
+                }
+            }
+        }
+        intersections
+    }
+
+    fn assert_matches_naive(points: &[(Point, Point)]) {
+        let segments = points.iter()
+            .map(|(p1, p2)| LineSegment::new(p1, p2))
+            .collect::<Vec<_>>();
+        let actual = segment_intersections(&segments);
+        let expected = naive_intersections(&segments);
+
+        let pairs = |v: &[(usize, usize, Point)]| v.iter().map(|(i, j, _)| (*i, *j)).collect::<Vec<_>>();
+        assert_eq!(pairs(&actual), pairs(&expected));
+        for ((_, _, p), (_, _, q)) in actual.iter().zip(expected.iter()) {
+            assert!(p.distance_to(q) < F64_ASSERT_PRECISION);
+        }
+    }
+
+    fn segment(x1: f64, y1: f64, x2: f64, y2: f64) -> (Point, Point) {
+        (Point::new(x1, y1), Point::new(x2, y2))
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(segment_intersections(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_crossing() {
+        let points = [segment(0.0, 0.0, 4.0, 4.0), segment(0.0, 4.0, 4.0, 0.0)];
+        let segments = points.iter()
+            .map(|(p1, p2)| LineSegment::new(p1, p2))
+            .collect::<Vec<_>>();
+        let intersections = segment_intersections(&segments);
+        assert_eq!(intersections, vec![(0, 1, Point::new(2.0, 2.0))]);
+    }
+
+    #[test]
+    fn test_polygon_edges() {
+        // Only adjacent edges of a simple polygon intersect
+        assert_matches_naive(&[
+            segment(0.0, 0.0, 4.0, 0.0),
+            segment(4.0, 0.0, 4.0, 4.0),
+            segment(4.0, 4.0, 2.0, 1.0),
+            segment(2.0, 1.0, 0.0, 4.0),
+            segment(0.0, 4.0, 0.0, 0.0),
+        ]);
+    }
+
+    #[test]
+    fn test_pentagram() {
+        let corners = (0..5)
+            .map(|i| {
+                let theta = i as f64 * 4.0 * std::f64::consts::PI / 5.0;
+                (theta.cos(), theta.sin())
+            })
+            .collect::<Vec<_>>();
+        let points = (0..5)
+            .map(|i| {
+                let (x1, y1) = corners[i];
+                let (x2, y2) = corners[(i + 1) % 5];
+                segment(x1, y1, x2, y2)
+            })
+            .collect::<Vec<_>>();
+        assert_matches_naive(&points);
+    }
+
+    #[test]
+    fn test_degenerate_configurations() {
+        assert_matches_naive(&[
+            // Vertical segment crossed by a horizontal one
+            segment(1.0, -2.0, 1.0, 2.0),
+            segment(0.0, 0.0, 3.0, 0.0),
+            // T-junction onto the horizontal segment
+            segment(2.0, 0.0, 2.0, 3.0),
+            // Collinear overlap with the horizontal segment
+            segment(2.5, 0.0, 5.0, 0.0),
+            // Zero-length segment on the horizontal segment
+            segment(0.5, 0.0, 0.5, 0.0),
+            // Several segments through a common point
+            segment(6.0, 0.0, 8.0, 2.0),
+            segment(6.0, 2.0, 8.0, 0.0),
+            segment(7.0, -1.0, 7.0, 3.0),
+            // Disjoint
+            segment(10.0, 10.0, 11.0, 11.0),
+        ]);
+    }
+
+    #[rstest]
+    fn test_matches_naive_random(
+        #[values(2, 5, 10, 30)] n: usize,
+        #[values(0, 1, 2, 3)] seed: u64
+    ) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut random = || rng.gen_range(-10.0..10.0);
+        let points = (0..n)
+            .map(|_| segment(random(), random(), random(), random()))
+            .collect::<Vec<_>>();
+        assert_matches_naive(&points);
+    }
+}
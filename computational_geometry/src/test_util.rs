@@ -0,0 +1,14 @@
+use crate::{point::Point, polygon::Polygon};
+
+// Helpers shared by the test modules of more than one file
+
+
+pub(crate) fn square(x: f64, y: f64, size: f64) -> Polygon {
+    // Axis-aligned and CCW with its lower left corner at (x, y)
+    Polygon::new(vec![
+        Point::new(x, y),
+        Point::new(x + size, y),
+        Point::new(x + size, y + size),
+        Point::new(x, y + size),
+    ])
+}
@@ -1,37 +1,189 @@
 use std::cell::OnceCell;
 
 use crate::{
-    point::Point,
+    geometry::Geometry,
+    line_segment::LineSegment,
+    point::{Point, Turn},
+    polygon::Polygon,
+    scalar::Scalar,
     vertex::Vertex,
 };
 
 
-pub struct Triangle<'a> {
-    pub p1: &'a Point,
-    pub p2: &'a Point,
-    pub p3: &'a Point,
-    area: OnceCell<f64>,
+pub struct Triangle<'a, T = f64> {
+    pub p1: &'a Point<T>,
+    pub p2: &'a Point<T>,
+    pub p3: &'a Point<T>,
+    area: OnceCell<T>,
 }
 
-impl<'a> Triangle<'a> {
-    pub fn new(p1: &'a Point, p2: &'a Point, p3: &'a Point) -> Triangle<'a> {
+impl<'a, T> Triangle<'a, T> {
+    pub fn new(p1: &'a Point<T>, p2: &'a Point<T>, p3: &'a Point<T>) -> Triangle<'a, T> {
         Triangle { p1, p2, p3, area: OnceCell::new() }
     }
+}
 
-    pub fn from_vertices(v1: &'a Vertex, v2: &'a Vertex, v3: &'a Vertex) -> Triangle<'a> {
-        Triangle::new(&v1.coords, &v2.coords, &v3.coords)
+impl<'a, T: Scalar> Triangle<'a, T> {
+    pub fn from_vertices(v1: &'a Vertex<T>, v2: &'a Vertex<T>, v3: &'a Vertex<T>) -> Triangle<'a, T> {
+        Triangle::new(v1.point(), v2.point(), v3.point())
     }
 
-    pub fn area(&self) -> f64 {
+    pub fn area(&self) -> T {
         *self.area.get_or_init(|| {
-            let t1 = (self.p2.x - self.p1.x) * (self.p3.y - self.p1.y);
-            let t2 = (self.p3.x - self.p1.x) * (self.p2.y - self.p1.y);
-            0.5 * (t1 - t2)
+            T::from_f64(0.5) * (self.p2 - self.p1).cross(&(self.p3 - self.p1))
         })
     }
 
+    pub fn edges(&self) -> [LineSegment<'a, T>; 3] {
+        [
+            LineSegment::new(self.p1, self.p2),
+            LineSegment::new(self.p2, self.p3),
+            LineSegment::new(self.p3, self.p1),
+        ]
+    }
+
     pub fn has_collinear_points(&self) -> bool {
-        self.area() == 0.0
+        self.area() == T::zero()
+    }
+
+    pub fn centroid(&self) -> Point<T> {
+        let three = T::from_f64(3.0);
+        let x = (self.p1.x + self.p2.x + self.p3.x) / three;
+        let y = (self.p1.y + self.p2.y + self.p3.y) / three;
+        Point::new(x, y)
+    }
+
+    pub fn contains_strict(&self, p: &Point<T>) -> bool {
+        // Strictly interior points only, so unlike `contains_point` the
+        // boundary is excluded and degenerate triangles contain nothing
+        let orientations = self.edges()
+            .iter()
+            .map(|e| p.orientation(e))
+            .collect::<Vec<_>>();
+        orientations.iter().all(|o| *o == Turn::Left)
+            || orientations.iter().all(|o| *o == Turn::Right)
+    }
+}
+
+// Angles and circles are only for f64
+impl<'a> Triangle<'a> {
+    pub fn min_angle(&self) -> f64 {
+        // Smallest interior angle in radians from the law of cosines,
+        // where the smallest angle is opposite the shortest side
+        let a = self.p2.distance_to(self.p3);
+        let b = self.p3.distance_to(self.p1);
+        let c = self.p1.distance_to(self.p2);
+        let (shortest, s1, s2) = match (a <= b, a <= c, b <= c) {
+            (true, true, _) => (a, b, c),
+            (false, _, true) => (b, a, c),
+            _ => (c, a, b),
+        };
+        let cos = (s1 * s1 + s2 * s2 - shortest * shortest) / (2.0 * s1 * s2);
+        cos.clamp(-1.0, 1.0).acos()
+    }
+
+    pub fn incenter(&self) -> Point {
+        // Vertices weighted by the length of their opposite side
+        let a = self.p2.distance_to(self.p3);
+        let b = self.p3.distance_to(self.p1);
+        let c = self.p1.distance_to(self.p2);
+        let perimeter = a + b + c;
+        let x = (a * self.p1.x + b * self.p2.x + c * self.p3.x) / perimeter;
+        let y = (a * self.p1.y + b * self.p2.y + c * self.p3.y) / perimeter;
+        Point::new(x, y)
+    }
+
+    pub fn in_circle(&self, p: &Point) -> bool {
+        // Sign of the standard in-circle determinant, which is positive
+        // when p is strictly inside the circumcircle assuming the 
+        // triangle is CCW (counter-clockwise)
+        let ax = self.p1.x - p.x;
+        let ay = self.p1.y - p.y;
+        let bx = self.p2.x - p.x;
+        let by = self.p2.y - p.y;
+        let cx = self.p3.x - p.x;
+        let cy = self.p3.y - p.y;
+        let a = ax * ax + ay * ay;
+        let b = bx * bx + by * by;
+        let c = cx * cx + cy * cy;
+        let det = ax * (by * c - b * cy) - ay * (bx * c - b * cx) + a * (bx * cy - by * cx);
+        det > 0.0
+    }
+
+    pub fn circumcenter(&self) -> Option<Point> {
+        if self.has_collinear_points() {
+            return None;
+        }
+        let (a, b, c) = (self.p1, self.p2, self.p3);
+        let a_sq = a.x * a.x + a.y * a.y;
+        let b_sq = b.x * b.x + b.y * b.y;
+        let c_sq = c.x * c.x + c.y * c.y;
+        // Equivalent to 4 times the signed area
+        let d = 2.0 * (a.x * (b.y - c.y) + b.x * (c.y - a.y) + c.x * (a.y - b.y));
+        let x = (a_sq * (b.y - c.y) + b_sq * (c.y - a.y) + c_sq * (a.y - b.y)) / d;
+        let y = (a_sq * (c.x - b.x) + b_sq * (a.x - c.x) + c_sq * (b.x - a.x)) / d;
+        Some(Point::new(x, y))
+    }
+
+    pub fn circumradius(&self) -> Option<f64> {
+        self.circumcenter().map(|center| center.distance_to(self.p1))
+    }
+
+    pub fn to_polygon(&self) -> Polygon {
+        // Vertices in the triangle's order so the polygon keeps its
+        // orientation. Collinear triangles give a degenerate polygon.
+        Polygon::new_unchecked(vec![self.p1.clone(), self.p2.clone(), self.p3.clone()])
+    }
+}
+
+// Exact predicates for integer coordinates evaluated in i128, None when
+// the determinant overflows, see the `Point<i64>` predicates
+impl Triangle<'_, i64> {
+    pub fn double_area(&self) -> Option<i128> {
+        // Signed, positive for CCW. Fits for coordinates within +/-2^62.
+        let (a, b, c) = (self.p1, self.p2, self.p3);
+        let left = (b.x as i128 - a.x as i128).checked_mul(c.y as i128 - a.y as i128)?;
+        let right = (c.x as i128 - a.x as i128).checked_mul(b.y as i128 - a.y as i128)?;
+        left.checked_sub(right)
+    }
+
+    pub fn in_circle(&self, p: &Point<i64>) -> Option<bool> {
+        // Same determinant as the f64 version, strictly inside the
+        // circumcircle of a CCW triangle. The degree 4 terms only fit in
+        // i128 for coordinates within +/-2^30.
+        let d = |q: &Point<i64>| (q.x as i128 - p.x as i128, q.y as i128 - p.y as i128);
+        let ((ax, ay), (bx, by), (cx, cy)) = (d(self.p1), d(self.p2), d(self.p3));
+        let norm = |x: i128, y: i128| x.checked_mul(x)?.checked_add(y.checked_mul(y)?);
+        let (a, b, c) = (norm(ax, ay)?, norm(bx, by)?, norm(cx, cy)?);
+        let minor = |x1: i128, y1: i128, x2: i128, y2: i128| {
+            x1.checked_mul(y2)?.checked_sub(y1.checked_mul(x2)?)
+        };
+        let det = ax.checked_mul(minor(by, b, cy, c)?)?
+            .checked_sub(ay.checked_mul(minor(bx, b, cx, c)?)?)?
+            .checked_add(a.checked_mul(minor(bx, by, cx, cy)?)?)?;
+        Some(det > 0)
+    }
+}
+
+
+impl Geometry for Triangle<'_> {
+    fn points(&self) -> Vec<&Point> {
+        vec![self.p1, self.p2, self.p3]
+    }
+
+    fn contains_point(&self, p: &Point) -> bool {
+        // Inside if the point is on the same side of every edge, which
+        // covers both orientations. Degenerate triangles are just their
+        // edges so that collinear points beyond them are excluded.
+        let edges = self.edges();
+        if self.has_collinear_points() {
+            return edges.iter().any(|e| e.contains_point(p));
+        }
+        let orientations = edges.iter()
+            .map(|e| p.orientation(e))
+            .collect::<Vec<_>>();
+        orientations.iter().all(|o| *o != Turn::Right)
+            || orientations.iter().all(|o| *o != Turn::Left)
     }
 }
 
@@ -39,8 +191,10 @@ impl<'a> Triangle<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use assert_approx_eq::assert_approx_eq;
     use itertools::Itertools;
-    use crate::vertex::VertexId;
+    use std::f64::consts::{FRAC_PI_3, FRAC_PI_4, SQRT_2};
+    use crate::{vertex::VertexId, F64_ASSERT_PRECISION};
 
     #[test]
     fn test_from_vertices() {
@@ -66,6 +220,80 @@ mod tests {
         assert_eq!(area, 6.0);
     }
 
+    #[test]
+    fn test_centroid_right_triangle() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(3.0, 0.0);
+        let c = Point::new(0.0, 4.0);
+        let centroid = Triangle::new(&a, &b, &c).centroid();
+        assert_approx_eq!(centroid.x, 1.0, F64_ASSERT_PRECISION);
+        assert_approx_eq!(centroid.y, 4.0 / 3.0, F64_ASSERT_PRECISION);
+    }
+
+    #[test]
+    fn test_incenter_right_triangle() {
+        // Inradius of a 3-4-5 right triangle is (3 + 4 - 5) / 2 = 1
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(3.0, 0.0);
+        let c = Point::new(0.0, 4.0);
+        let incenter = Triangle::new(&a, &b, &c).incenter();
+        assert_approx_eq!(incenter.x, 1.0, F64_ASSERT_PRECISION);
+        assert_approx_eq!(incenter.y, 1.0, F64_ASSERT_PRECISION);
+    }
+
+    #[test]
+    fn test_min_angle() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(1.0, 0.0);
+        let c = Point::new(0.5, 0.5 * 3.0f64.sqrt());
+        assert_approx_eq!(Triangle::new(&a, &b, &c).min_angle(), FRAC_PI_3, F64_ASSERT_PRECISION);
+
+        // Right triangle 1-1-sqrt(2) has two 45 degree angles
+        let d = Point::new(0.0, 1.0);
+        assert_approx_eq!(Triangle::new(&a, &b, &d).min_angle(), FRAC_PI_4, F64_ASSERT_PRECISION);
+        assert_approx_eq!(Triangle::new(&d, &b, &a).min_angle(), FRAC_PI_4, F64_ASSERT_PRECISION);
+
+        let e = Point::new(10.0, 1.0);
+        assert!(Triangle::new(&a, &b, &e).min_angle() < 0.1);
+    }
+
+    #[test]
+    fn test_circumcenter_right_triangle() {
+        // Circumcenter of a right triangle is the hypotenuse midpoint
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(1.0, 0.0);
+        let c = Point::new(0.0, 1.0);
+        let triangle = Triangle::new(&a, &b, &c);
+        assert_eq!(triangle.circumcenter(), Some(Point::new(0.5, 0.5)));
+        assert_approx_eq!(triangle.circumradius().unwrap(), 0.5 * SQRT_2, F64_ASSERT_PRECISION);
+    }
+
+    #[test]
+    fn test_circumcenter_collinear() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(1.0, 1.0);
+        let c = Point::new(2.0, 2.0);
+        let triangle = Triangle::new(&a, &b, &c);
+        assert_eq!(triangle.circumcenter(), None);
+        assert_eq!(triangle.circumradius(), None);
+    }
+
+    #[test]
+    fn test_in_circle() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(1.0, 0.0);
+        let c = Point::new(0.0, 1.0);
+        let triangle = Triangle::new(&a, &b, &c);
+        // Vertices and the opposite corner of the unit square lie on
+        // the circumcircle so they are not strictly inside
+        assert!( triangle.in_circle(&Point::new(0.5, 0.5)));
+        assert!( triangle.in_circle(&Point::new(0.9, 0.9)));
+        assert!(!triangle.in_circle(&Point::new(1.0, 1.0)));
+        assert!(!triangle.in_circle(&a));
+        assert!(!triangle.in_circle(&Point::new(1.5, 1.5)));
+        assert!(!triangle.in_circle(&Point::new(-1.0, 0.5)));
+    }
+
     // TODO want some better unit tests for the triangle area
 
     #[test]
@@ -100,6 +328,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_to_polygon() {
+        let (a, b, c) = (Point::new(1.0, 1.0), Point::new(4.0, 2.0), Point::new(2.0, 5.0));
+        for triangle in [Triangle::new(&a, &b, &c), Triangle::new(&a, &c, &b)] {
+            let polygon = triangle.to_polygon();
+            assert_approx_eq!(polygon.area(), triangle.area().abs(), F64_ASSERT_PRECISION);
+            assert_approx_eq!(polygon.signed_area(), triangle.area(), F64_ASSERT_PRECISION);
+            let points = polygon.boundary_vertices()
+                .iter()
+                .map(|v| v.point().clone())
+                .collect::<Vec<_>>();
+            assert_eq!(points, vec![triangle.p1.clone(), triangle.p2.clone(), triangle.p3.clone()]);
+        }
+    }
+
     #[test]
     fn test_area_collinear() {
         let a = Point::new(0.0, 0.0);
@@ -108,8 +351,7 @@ mod tests {
 
         // This is choice with replacement over a 3-tuple, so there are
         // 3 * 3 * 3 = 27 total options and this generates all of them.
-        let all_combos = std::iter::repeat(vec![&a, &b, &c].into_iter())
-            .take(3)
+        let all_combos = std::iter::repeat(vec![&a, &b, &c].into_iter()).take(3)
             .multi_cartesian_product();
         
         for points in all_combos {
@@ -129,4 +371,76 @@ mod tests {
             }
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_contains_edge_point() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(4.0, 0.0);
+        let c = Point::new(0.0, 4.0);
+        for triangle in [Triangle::new(&a, &b, &c), Triangle::new(&a, &c, &b)] {
+            // Edge interior and vertex are only inside non-strictly
+            for p in [Point::new(2.0, 0.0), Point::new(2.0, 2.0), Point::new(0.0, 4.0)] {
+                assert!( triangle.contains_point(&p));
+                assert!(!triangle.contains_strict(&p));
+            }
+            assert!(triangle.contains_point(&Point::new(1.0, 1.0)));
+            assert!(triangle.contains_strict(&Point::new(1.0, 1.0)));
+            assert!(!triangle.contains_point(&Point::new(3.0, 3.0)));
+            assert!(!triangle.contains_strict(&Point::new(3.0, 3.0)));
+        }
+    }
+
+    #[test]
+    fn test_contains_strict_degenerate() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(2.0, 2.0);
+        let c = Point::new(4.0, 4.0);
+        let triangle = Triangle::new(&a, &b, &c);
+        assert!( triangle.contains_point(&Point::new(1.0, 1.0)));
+        assert!(!triangle.contains_strict(&Point::new(1.0, 1.0)));
+    }
+
+    #[test]
+    fn test_i64_in_circle() {
+        // All four corners of the square are on the same circle
+        let a = Point::new(0i64, 0);
+        let b = Point::new(4i64, 0);
+        let c = Point::new(0i64, 4);
+        let triangle = Triangle::new(&a, &b, &c);
+        assert_eq!(triangle.double_area(), Some(16));
+        assert_eq!(triangle.in_circle(&Point::new(1, 1)), Some(true));
+        assert_eq!(triangle.in_circle(&Point::new(3, 3)), Some(true));
+        assert_eq!(triangle.in_circle(&Point::new(4, 4)), Some(false));
+        assert_eq!(triangle.in_circle(&Point::new(5, 5)), Some(false));
+
+        // Nearly cocircular at the largest supported coordinates
+        let r = 1i64 << 30;
+        let (a, b, c) = (Point::new(-r, 0), Point::new(r, 0), Point::new(0, r));
+        let triangle = Triangle::new(&a, &b, &c);
+        assert_eq!(triangle.in_circle(&Point::new(0, -r)), Some(false));
+        assert_eq!(triangle.in_circle(&Point::new(0, 1 - r)), Some(true));
+
+        // Beyond that the determinant overflows
+        let r = 1i64 << 40;
+        let (a, b, c) = (Point::new(-r, 0), Point::new(r, 0), Point::new(0, r));
+        let triangle = Triangle::new(&a, &b, &c);
+        assert_eq!(triangle.in_circle(&Point::new(0, -r)), None);
+        let m = Point::new(i64::MAX, i64::MAX);
+        let n = Point::new(i64::MIN, i64::MAX);
+        let o = Point::new(i64::MIN, i64::MIN);
+        assert_eq!(Triangle::new(&o, &m, &n).double_area(), None);
+    }
+
+    #[test]
+    fn test_f32() {
+        let a = Point::new(0.0f32, 0.0);
+        let b = Point::new(3.0f32, 0.0);
+        let c = Point::new(0.0f32, 4.0);
+        let triangle = Triangle::new(&a, &b, &c);
+        assert_eq!(triangle.area(), 6.0f32);
+        assert_eq!(Triangle::new(&a, &c, &b).area(), -6.0f32);
+        assert_eq!(triangle.centroid(), Point::new(1.0f32, 4.0 / 3.0));
+        assert!(triangle.contains_strict(&Point::new(1.0, 1.0)));
+        assert!(!triangle.contains_strict(&Point::new(3.0, 4.0)));
+    }
+}
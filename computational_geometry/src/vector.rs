@@ -0,0 +1,206 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::{point::Point, scalar::Scalar};
+
+// A displacement in the plane, as opposed to a Point which is a
+// position. Subtracting points gives the vector between them and adding
+// a vector to a point moves the point, directions and normals are
+// vectors. Components have the same scalar type as the points they
+// come from, f64 unless another is given.
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vector2<T = f64> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Vector2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Vector2 { x, y }
+    }
+}
+
+impl<T: Scalar> Vector2<T> {
+    pub fn between(from: &Point<T>, to: &Point<T>) -> Self {
+        Vector2::new(to.x - from.x, to.y - from.y)
+    }
+
+    pub fn dot(&self, other: &Vector2<T>) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    pub fn cross(&self, other: &Vector2<T>) -> T {
+        // Positive when other is CCW from self, zero if they're parallel
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn norm(&self) -> T {
+        self.norm_squared().sqrt()
+    }
+
+    pub fn norm_squared(&self) -> T {
+        self.dot(self)
+    }
+
+    pub fn normalized(&self) -> Vector2<T> {
+        // NaN components for the zero vector
+        let norm = self.norm();
+        Vector2::new(self.x / norm, self.y / norm)
+    }
+
+    pub fn perpendicular(&self) -> Vector2<T> {
+        // Rotated a quarter turn CCW
+        Vector2::new(-self.y, self.x)
+    }
+}
+
+
+impl<T: Scalar> Add for Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn add(self, other: Vector2<T>) -> Vector2<T> {
+        Vector2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Scalar> Sub for Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn sub(self, other: Vector2<T>) -> Vector2<T> {
+        Vector2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Scalar> Neg for Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn neg(self) -> Vector2<T> {
+        Vector2::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Scalar> Mul<T> for Vector2<T> {
+    type Output = Vector2<T>;
+
+    fn mul(self, scalar: T) -> Vector2<T> {
+        Vector2::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl<T: Scalar> Add<Vector2<T>> for &Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, v: Vector2<T>) -> Point<T> {
+        Point::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl<T: Scalar> Add<Vector2<T>> for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, v: Vector2<T>) -> Point<T> {
+        &self + v
+    }
+}
+
+impl<T: Scalar> Sub for &Point<T> {
+    type Output = Vector2<T>;
+
+    fn sub(self, other: &Point<T>) -> Vector2<T> {
+        Vector2::between(other, self)
+    }
+}
+
+impl<T: Scalar> Sub for Point<T> {
+    type Output = Vector2<T>;
+
+    fn sub(self, other: Point<T>) -> Vector2<T> {
+        &self - &other
+    }
+}
+
+impl<T> From<Point<T>> for Vector2<T> {
+    // Position vector from the origin
+    fn from(p: Point<T>) -> Self {
+        Vector2::new(p.x, p.y)
+    }
+}
+
+impl<T> From<Vector2<T>> for Point<T> {
+    fn from(v: Vector2<T>) -> Self {
+        Point::new(v.x, v.y)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+    use rstest::rstest;
+    use crate::F64_ASSERT_PRECISION;
+
+    #[test]
+    fn test_between() {
+        let a = Point::new(1.0, 2.0);
+        let b = Point::new(4.0, -2.0);
+        let v = Vector2::between(&a, &b);
+        assert_eq!(v, Vector2::new(3.0, -4.0));
+        assert_eq!(&a + v, b);
+        assert_eq!(Vector2::between(&b, &a), -v);
+        assert_eq!(&b - &a, v);
+        assert_eq!(b.clone() - a.clone(), v);
+        assert_eq!(a.clone() + v, b);
+        assert_eq!(Point::from(v), Point::new(3.0, -4.0));
+        assert_eq!(Vector2::from(b), Vector2::new(4.0, -2.0));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let u = Vector2::new(1.0, 2.0);
+        let v = Vector2::new(-3.0, 0.5);
+        assert_eq!(u + v, Vector2::new(-2.0, 2.5));
+        assert_eq!(u - v, Vector2::new(4.0, 1.5));
+        assert_eq!(u * 2.0, Vector2::new(2.0, 4.0));
+        assert_eq!(-u, Vector2::new(-1.0, -2.0));
+    }
+
+    #[test]
+    fn test_dot() {
+        let u = Vector2::new(1.0, 2.0);
+        assert_eq!(u.dot(&Vector2::new(3.0, 4.0)), 11.0);
+        assert_eq!(u.dot(&u.perpendicular()), 0.0);
+        assert_eq!(u.dot(&u), u.norm_squared());
+    }
+
+    #[rstest]
+    #[case(Vector2::new(0.0, 1.0), 2.0)]
+    #[case(Vector2::new(0.0, -1.0), -2.0)]
+    #[case(Vector2::new(4.0, 2.0), 0.0)]
+    #[case(Vector2::new(-2.0, -1.0), 0.0)]
+    fn test_cross(#[case] v: Vector2, #[case] expected: f64) {
+        let u = Vector2::new(2.0, 1.0);
+        assert_eq!(u.cross(&v), expected);
+        assert_eq!(v.cross(&u), -expected);
+        assert!(u.cross(&u.perpendicular()) > 0.0);
+    }
+
+    #[rstest]
+    #[case(Vector2::new(3.0, 4.0), 5.0)]
+    #[case(Vector2::new(-1.0, 0.0), 1.0)]
+    #[case(Vector2::new(0.0, 0.0), 0.0)]
+    fn test_norm(#[case] v: Vector2, #[case] expected: f64) {
+        assert_eq!(v.norm(), expected);
+        assert_eq!(v.norm_squared(), expected * expected);
+    }
+
+    #[test]
+    fn test_normalized() {
+        let v = Vector2::new(3.0, -4.0).normalized();
+        assert_approx_eq!(v.norm(), 1.0, F64_ASSERT_PRECISION);
+        assert_approx_eq!(v.x, 0.6, F64_ASSERT_PRECISION);
+        assert_approx_eq!(v.y, -0.8, F64_ASSERT_PRECISION);
+        assert!(Vector2::new(0.0f64, 0.0).normalized().x.is_nan());
+    }
+}
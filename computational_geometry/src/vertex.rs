@@ -2,7 +2,8 @@ use std::fmt;
 
 use crate::{
     line_segment::LineSegment,
-    point::Point,
+    point::{Point, Turn},
+    scalar::Scalar,
 };
 
 
@@ -29,42 +30,66 @@ impl fmt::Display for VertexId {
 
 
 #[derive(Clone, Debug, PartialEq)]
-pub struct Vertex {
-    pub coords: Point,
+pub struct Vertex<T = f64> {
+    #[deprecated(note = "use `point`, `x` or `y` instead")]
+    pub coords: Point<T>,
     pub id: VertexId,
     pub prev: VertexId,
     pub next: VertexId,
 }
 
-impl Vertex {    
-    pub fn new(coords: Point, id: VertexId, prev: VertexId, next: VertexId) -> Vertex {
+// Coordinates are read through `point`, `x` and `y` so callers don't
+// depend on how the vertex stores them
+#[allow(deprecated)]
+impl<T: Scalar> Vertex<T> {
+    pub fn new(coords: Point<T>, id: VertexId, prev: VertexId, next: VertexId) -> Vertex<T> {
        Vertex { coords, id, prev, next }
     }
 
-    pub fn between(&self, a: &Vertex, b: &Vertex) -> bool {
+    pub fn point(&self) -> &Point<T> {
+        &self.coords
+    }
+
+    pub fn x(&self) -> T {
+        self.coords.x
+    }
+
+    pub fn y(&self) -> T {
+        self.coords.y
+    }
+
+    pub fn between(&self, a: &Vertex<T>, b: &Vertex<T>) -> bool {
         self.coords.between(&a.coords, &b.coords)
     }
 
-    pub fn left(&self, ab: &LineSegment) -> bool {
+    pub fn orientation(&self, ab: &LineSegment<T>) -> Turn {
+        self.coords.orientation(ab)
+    }
+
+    pub fn left(&self, ab: &LineSegment<T>) -> bool {
         self.coords.left(ab)
     }
 
-    pub fn left_on(&self, ab: &LineSegment) -> bool {
+    pub fn left_on(&self, ab: &LineSegment<T>) -> bool {
         self.coords.left_on(ab)
     }
 
-    pub fn translate(&mut self, x: f64, y: f64) {
+    pub fn translate(&mut self, x: T, y: T) {
         self.coords.translate(x, y)
     }
 
-    pub fn rotate_about_origin(&mut self, radians: f64) {
+    pub fn rotate_about_origin(&mut self, radians: T) {
         self.coords.rotate_about_origin(radians);
     }
 
-    pub fn rotate_about_point(&mut self, radians: f64, point: &Point) {
+    pub fn rotate_about_point(&mut self, radians: T, point: &Point<T>) {
         self.coords.rotate_about_point(radians, point);
     }
 
+    pub fn scale_about_point(&mut self, factor: T, point: &Point<T>) {
+        self.coords.scale_about_point(factor, point);
+    }
+
     pub fn round_coordinates(&mut self) {
         self.coords.round();
     }
@@ -1,23 +1,24 @@
 use std::collections::{hash_map, HashMap};
 
 use crate::point::Point;
+use crate::scalar::Scalar;
 use crate::vertex::{Vertex, VertexId};
 
 
 #[derive(Clone, Debug, PartialEq)]
-pub struct VertexMap {
-    map: HashMap<VertexId, Vertex>,
+pub struct VertexMap<T = f64> {
+    map: HashMap<VertexId, Vertex<T>>,
 }
 
-impl VertexMap {
-    pub fn new(points: Vec<Point>) -> Self {
+impl<T: Scalar> VertexMap<T> {
+    pub fn new(points: Vec<Point<T>>) -> Self {
         let mut map = HashMap::new();
 
         // TODO currently the IDs are simply generated starting
-        // at 0 and incrementing. If you want to keep this route,
-        // will need to track index on self so that new vertices
-        // could be added. Tried using unique_id::SequenceGenerator
-        // but it was global which was harder to test with
+        // at 0 and incrementing, vertices inserted later search for
+        // an unused ID (see `insert_after`). Tried using
+        // unique_id::SequenceGenerator but it was global which was
+        // harder to test with
         let num_points = points.len();
         let vertex_ids = (0..num_points)
             .map(VertexId::from)
@@ -34,14 +35,30 @@ impl VertexMap {
         VertexMap { map }
     }
 
-    pub fn get(&self, k: &VertexId) -> &Vertex {
+    pub fn get(&self, k: &VertexId) -> &Vertex<T> {
         self.map.get(k).unwrap()
     }
 
-    pub fn get_mut(&mut self, k: &VertexId) -> &mut Vertex{
+    pub fn get_optional(&self, k: &VertexId) -> Option<&Vertex<T>> {
+        self.map.get(k)
+    }
+
+    pub fn next(&self, k: &VertexId) -> &Vertex<T> {
+        self.get(&self.get(k).next)
+    }
+
+    pub fn prev(&self, k: &VertexId) -> &Vertex<T> {
+        self.get(&self.get(k).prev)
+    }
+
+    pub fn get_mut(&mut self, k: &VertexId) -> &mut Vertex<T> {
         self.map.get_mut(k).unwrap()
     }
 
+    pub fn contains_key(&self, k: &VertexId) -> bool {
+        self.map.contains_key(k)
+    }
+
     pub fn len(&self) -> usize {
         self.map.len()
     }
@@ -50,36 +67,52 @@ impl VertexMap {
         self.map.is_empty()
     }
 
-    pub fn remove(&mut self, k: &VertexId) -> Vertex {
+    pub fn remove(&mut self, k: &VertexId) -> Vertex<T> {
+        // Neighbors of the removed vertex are linked to each other
         let v = self.map.remove(k).unwrap();
         self.update_next(&v.prev, &v.next);
         self.update_prev(&v.next, &v.prev);
         v
     }
 
-    pub fn values(&self) -> hash_map::Values<'_, VertexId, Vertex> {
+    pub fn insert_after(&mut self, k: &VertexId, p: Point<T>) -> VertexId {
+        // New vertex goes between k and its next vertex. IDs start
+        // from the current size so the search for a free one is short
+        // unless many vertices were removed.
+        let id = (self.map.len()..)
+            .map(VertexId::from)
+            .find(|id| !self.map.contains_key(id))
+            .unwrap();
+        let next = self.get(k).next;
+        self.map.insert(id, Vertex::new(p, id, *k, next));
+        self.update_next(k, &id);
+        self.update_prev(&next, &id);
+        id
+    }
+
+    pub fn values(&self) -> hash_map::Values<'_, VertexId, Vertex<T>> {
         self.map.values()
     }
 
-    pub fn values_mut(&mut self) -> hash_map::ValuesMut<'_, VertexId, Vertex> {
+    pub fn values_mut(&mut self) -> hash_map::ValuesMut<'_, VertexId, Vertex<T>> {
         self.map.values_mut()
     }
 
-    pub fn sorted_vertices(&self) -> Vec<&Vertex> {
+    pub fn sorted_vertices(&self) -> Vec<&Vertex<T>> {
         let mut vertices = self.values()
-            .collect::<Vec<&Vertex>>();
-        vertices.sort_by(|a, b| a.id.cmp(&b.id));
+            .collect::<Vec<&Vertex<T>>>();
+        vertices.sort_by_key(|v| v.id);
         vertices
     }
 
-    pub fn sorted_points(&self) -> Vec<Point> {
+    pub fn sorted_points(&self) -> Vec<Point<T>> {
         self.sorted_vertices()
             .iter()
-            .map(|v| v.coords.clone())
-            .collect::<Vec<Point>>()
+            .map(|v| v.point().clone())
+            .collect::<Vec<Point<T>>>()
     }
 
-    pub fn anchor(&self) -> &Vertex {
+    pub fn anchor(&self) -> &Vertex<T> {
         // TODO I'm not yet convinced this is something I want, ultimately
         // need something to initiate algorithms in the vertex chain.
         // Could consider only exposing keys and then having polygon gen
@@ -87,22 +120,6 @@ impl VertexMap {
         self.values().collect::<Vec<_>>()[0]
     }
 
-    pub fn min_x(&self) -> f64 {
-        self.values().fold(f64::MAX, |acc, v| acc.min(v.coords.x))
-    }
-
-    pub fn max_x(&self) -> f64 {
-        self.values().fold(f64::MIN, |acc, v| acc.max(v.coords.x))
-    }
-
-    pub fn min_y(&self) -> f64 {
-        self.values().fold(f64::MAX, |acc, v| acc.min(v.coords.y))
-    }
-
-    pub fn max_y(&self) -> f64 {
-        self.values().fold(f64::MIN, |acc, v| acc.max(v.coords.y))
-    }
-
     pub fn update_next(&mut self, k: &VertexId, next: &VertexId) {
         self.get_mut(k).next = *next;
     }
@@ -111,24 +128,36 @@ impl VertexMap {
         self.get_mut(k).prev = *prev;
     }
 
-    pub fn translate(&mut self, x: f64, y: f64) {
+    pub fn reverse(&mut self) {
+        for v in self.values_mut() {
+            std::mem::swap(&mut v.prev, &mut v.next);
+        }
+    }
+
+    pub fn translate(&mut self, x: T, y: T) {
         for v in self.values_mut() {
             v.translate(x, y);
         }
     }
 
-    pub fn rotate_vertices_about_origin(&mut self, radians: f64) {
+    pub fn rotate_vertices_about_origin(&mut self, radians: T) {
         for v in self.values_mut() {
             v.rotate_about_origin(radians);
         }
     }
 
-    pub fn rotate_vertices_about_point(&mut self, radians: f64, point: &Point) {
+    pub fn rotate_vertices_about_point(&mut self, radians: T, point: &Point<T>) {
         for v in self.values_mut() {
             v.rotate_about_point(radians, point);
         }
     }
 
+    pub fn scale_vertices_about_point(&mut self, factor: T, point: &Point<T>) {
+        for v in self.values_mut() {
+            v.scale_about_point(factor, point);
+        }
+    }
+
     pub fn round_coordinates(&mut self) {
         for v in self.values_mut() {
             v.round_coordinates();
@@ -136,6 +165,25 @@ impl VertexMap {
     }
 }
 
+// Extents have f64::MAX and f64::MIN as their empty values
+impl VertexMap {
+    pub fn min_x(&self) -> f64 {
+        self.values().fold(f64::MAX, |acc, v| acc.min(v.x()))
+    }
+
+    pub fn max_x(&self) -> f64 {
+        self.values().fold(f64::MIN, |acc, v| acc.max(v.x()))
+    }
+
+    pub fn min_y(&self) -> f64 {
+        self.values().fold(f64::MAX, |acc, v| acc.min(v.y()))
+    }
+
+    pub fn max_y(&self) -> f64 {
+        self.values().fold(f64::MIN, |acc, v| acc.max(v.y()))
+    }
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -155,4 +203,97 @@ mod tests {
         assert_eq!(vmap.min_y(), -3.0);
         assert_eq!(vmap.max_y(), 8.0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_reverse() {
+        let p1 = Point::new(0.0, 0.0);
+        let p2 = Point::new(1.0, 0.0);
+        let p3 = Point::new(0.0, 1.0);
+        let mut vmap = VertexMap::new(vec![p1, p2, p3]);
+        vmap.reverse();
+        let id0 = VertexId::from(0u32);
+        let id1 = VertexId::from(1u32);
+        let id2 = VertexId::from(2u32);
+        assert_eq!(vmap.get(&id0).next, id2);
+        assert_eq!(vmap.get(&id0).prev, id1);
+        assert_eq!(vmap.get(&id1).next, id0);
+        assert_eq!(vmap.get(&id1).prev, id2);
+        assert_eq!(vmap.get(&id2).next, id1);
+        assert_eq!(vmap.get(&id2).prev, id0);
+    }
+
+    #[test]
+    fn test_next_prev() {
+        let p1 = Point::new(0.0, 0.0);
+        let p2 = Point::new(1.0, 0.0);
+        let p3 = Point::new(0.0, 1.0);
+        let vmap = VertexMap::new(vec![p1, p2, p3]);
+        let id0 = VertexId::from(0u32);
+        let id1 = VertexId::from(1u32);
+        let id2 = VertexId::from(2u32);
+        assert_eq!(vmap.next(&id0).id, id1);
+        assert_eq!(vmap.prev(&id0).id, id2);
+        assert_eq!(vmap.next(&id2).id, id0);
+        assert_eq!(vmap.prev(&id2).id, id1);
+    }
+
+    #[test]
+    fn test_get_optional() {
+        let p1 = Point::new(0.0, 0.0);
+        let p2 = Point::new(1.0, 0.0);
+        let p3 = Point::new(0.0, 1.0);
+        let vmap = VertexMap::new(vec![p1, p2, p3]);
+        assert_eq!(vmap.get_optional(&VertexId::from(1u32)).unwrap().point(), &Point::new(1.0, 0.0));
+        assert!(vmap.get_optional(&VertexId::from(3u32)).is_none());
+    }
+
+    #[test]
+    fn test_remove_relinks_neighbors() {
+        let p1 = Point::new(0.0, 0.0);
+        let p2 = Point::new(1.0, 0.0);
+        let p3 = Point::new(1.0, 1.0);
+        let p4 = Point::new(0.0, 1.0);
+        let mut vmap = VertexMap::new(vec![p1, p2, p3, p4]);
+        let id0 = VertexId::from(0u32);
+        let id1 = VertexId::from(1u32);
+        let id2 = VertexId::from(2u32);
+        let id3 = VertexId::from(3u32);
+
+        let v = vmap.remove(&id1);
+        assert_eq!(v.prev, id0);
+        assert_eq!(v.next, id2);
+        assert_eq!(vmap.len(), 3);
+        assert!(!vmap.contains_key(&id1));
+        assert_eq!(vmap.next(&id0).id, id2);
+        assert_eq!(vmap.prev(&id2).id, id0);
+
+        vmap.remove(&id0);
+        assert_eq!(vmap.next(&id3).id, id2);
+        assert_eq!(vmap.prev(&id2).id, id3);
+        assert_eq!(vmap.next(&id2).id, id3);
+        assert_eq!(vmap.prev(&id3).id, id2);
+    }
+
+    #[test]
+    fn test_insert_after() {
+        let p1 = Point::new(0.0, 0.0);
+        let p2 = Point::new(1.0, 0.0);
+        let p3 = Point::new(1.0, 1.0);
+        let mut vmap = VertexMap::new(vec![p1, p2, p3]);
+        let id0 = VertexId::from(0u32);
+        let id1 = VertexId::from(1u32);
+
+        let id = vmap.insert_after(&id0, Point::new(0.5, -0.5));
+        assert_eq!(id, VertexId::from(3u32));
+        assert_eq!(vmap.len(), 4);
+        assert_eq!(vmap.next(&id0).id, id);
+        assert_eq!(vmap.prev(&id1).id, id);
+        assert_eq!(vmap.prev(&id).id, id0);
+        assert_eq!(vmap.next(&id).id, id1);
+
+        // Removed IDs below the current size aren't reused
+        vmap.remove(&id0);
+        let id_new = vmap.insert_after(&id, Point::new(0.2, 0.2));
+        assert_eq!(id_new, VertexId::from(4u32));
+    }
+}
@@ -1,14 +1,16 @@
-use egui::Response;
+use egui::{Align2, Response};
 use egui_plot::{
-    CoordinatesFormatter, Corner, Line, 
-    Plot, Points, Polygon as PlotPolygon
+    CoordinatesFormatter, Corner, Line, Plot, PlotPoint,
+    PlotUi, Points, Polygon as PlotPolygon, Text
 };
 use std::collections::HashMap;
 use std::fmt;
 
 use computational_geometry::{
+    convex_hull::{AklToussaint, ChanHull, ConvexHullComputer, HullStep, MonotoneChain},
     point::Point,
-    polygon::Polygon,
+    polygon::{EarStrategy, Polygon},
+    vertex::VertexId,
 };
 
 use crate::app::RESULT_DIR;
@@ -18,6 +20,8 @@ use crate::app::RESULT_DIR;
 enum Visualization {
     Polygon,
     Triangulation,
+    ConvexHull,
+    HullSteps,
 }
 
 impl fmt::Display for Visualization {
@@ -27,19 +31,80 @@ impl fmt::Display for Visualization {
 }
 
 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum HullAlgorithm {
+    MonotoneChain,
+    Chan,
+    AklToussaint,
+}
+
+impl HullAlgorithm {
+    const ALL: [HullAlgorithm; 3] = [
+        HullAlgorithm::MonotoneChain,
+        HullAlgorithm::Chan,
+        HullAlgorithm::AklToussaint,
+    ];
+
+    fn computer(&self) -> Box<dyn ConvexHullComputer> {
+        match self {
+            HullAlgorithm::MonotoneChain => Box::new(MonotoneChain),
+            HullAlgorithm::Chan => Box::new(ChanHull),
+            HullAlgorithm::AklToussaint => Box::new(AklToussaint::new(Box::new(MonotoneChain))),
+        }
+    }
+}
+
+impl fmt::Display for HullAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum LabelKind {
+    // The vertex's ID in the polygon
+    Id,
+    // Position of the vertex along the boundary
+    Index,
+}
+
+impl fmt::Display for LabelKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+
+type TrianglePoints = Vec<(Point, Point, Point)>;
+
+
 //#[derive(PartialEq)]
 pub struct PolygonVisualizer {
     points: HashMap<String, Vec<[f64; 2]>>,
-    triangulations: HashMap<String, Vec<(Point, Point, Point)>>,
+    // Boundary order, so a vertex's index is its position here
+    vertex_ids: HashMap<String, Vec<VertexId>>,
+    polygons: HashMap<String, Polygon>,
+    triangulations: HashMap<String, Result<TrianglePoints, String>>,
+    hulls: HashMap<(String, HullAlgorithm), Vec<[f64; 2]>>,
+    // Traced the first time the hull steps are shown for a polygon
+    hull_steps: HashMap<String, Vec<HullStep>>,
     line_width: f32,
     point_radius: f32,
     selected_visualization: Visualization,
+    selected_hull_algorithm: HullAlgorithm,
+    show_labels: bool,
+    label_kind: LabelKind,
+    selected_hull_step: usize,
 }
 
 impl Default for PolygonVisualizer {
     fn default() -> Self {
         let mut points = HashMap::new();
+        let mut vertex_ids = HashMap::new();
         let mut triangulations = HashMap::new();
+        let mut hulls = HashMap::new();
+        let mut polygons = HashMap::new();
         
         for file in RESULT_DIR.files() {
             let stem = String::from(file.path().file_stem().unwrap().to_str().unwrap());
@@ -47,9 +112,14 @@ impl Default for PolygonVisualizer {
             let polygon_points: Vec<_> = serde_json::from_str::<Vec<Point>>(&contents)
                 .unwrap();
 
-            let mut plot_points: Vec<_> = polygon_points
+            let polygon = Polygon::new(polygon_points);
+
+            // Same boundary order as the points so labels line up
+            let ids = polygon.boundary_vertices().iter().map(|v| v.id).collect();
+            vertex_ids.insert(stem.clone(), ids);
+            let mut plot_points: Vec<_> = polygon.to_points()
                 .iter()
-                .map(|p: &Point| [p.x, p.y])
+                .map(|p| [p.x, p.y])
                 .collect();
             // Pushing first to end so it closes the chain, probably
             // only want to do this for line points since it
@@ -57,18 +127,45 @@ impl Default for PolygonVisualizer {
             plot_points.push(*plot_points.first().unwrap());
             points.insert(stem.clone(), plot_points);
 
-            let polygon = Polygon::new(polygon_points);
-            let triangulation_points = polygon.triangulation()
-                .to_points();
+            // Avoiding slivers as much as possible since they render badly
+            let triangulation_points = polygon.triangulation_with_strategy(EarStrategy::MaxMinAngle)
+                .map(|t| t.to_points())
+                .map_err(|e| {
+                    log::error!("Failed to triangulate {stem}: {e}");
+                    e.to_string()
+                });
             triangulations.insert(stem.clone(), triangulation_points);
+
+            for algorithm in HullAlgorithm::ALL {
+                // Collinear vertices have no hull polygon to draw
+                let Some(hull) = algorithm.computer().convex_hull(&polygon) else {
+                    log::error!("Convex hull of {stem} is degenerate");
+                    continue;
+                };
+                let mut hull_points: Vec<_> = hull.to_points()
+                    .iter()
+                    .map(|p| [p.x, p.y])
+                    .collect();
+                hull_points.push(*hull_points.first().unwrap());
+                hulls.insert((stem.clone(), algorithm), hull_points);
+            }
+            polygons.insert(stem.clone(), polygon);
         }
 
         Self { 
             points,
+            vertex_ids,
+            polygons,
             triangulations,
+            hulls,
+            hull_steps: HashMap::new(),
             line_width: 4.0, 
             point_radius: 8.0, 
             selected_visualization: Visualization::Polygon,
+            selected_hull_algorithm: HullAlgorithm::MonotoneChain,
+            show_labels: false,
+            label_kind: LabelKind::Id,
+            selected_hull_step: 0,
         }
     }
 }
@@ -87,6 +184,50 @@ impl PolygonVisualizer {
                 Visualization::Triangulation,
                 Visualization::Triangulation.to_string(),
             );
+            ui.selectable_value(
+                &mut self.selected_visualization, 
+                Visualization::ConvexHull,
+                Visualization::ConvexHull.to_string(),
+            );
+            ui.selectable_value(
+                &mut self.selected_visualization, 
+                Visualization::HullSteps,
+                Visualization::HullSteps.to_string(),
+            );
+            if self.selected_visualization == Visualization::HullSteps {
+                let num_steps = self.hull_steps(name).len();
+                self.selected_hull_step = self.selected_hull_step.min(num_steps - 1);
+                if ui.button("<").clicked() {
+                    self.selected_hull_step = self.selected_hull_step.saturating_sub(1);
+                }
+                ui.add(egui::Slider::new(&mut self.selected_hull_step, 0..=num_steps - 1).text("Step"));
+                if ui.button(">").clicked() {
+                    self.selected_hull_step = (self.selected_hull_step + 1).min(num_steps - 1);
+                }
+            }
+            if self.selected_visualization == Visualization::ConvexHull {
+                egui::ComboBox::from_label("Algorithm")
+                    .selected_text(self.selected_hull_algorithm.to_string())
+                    .show_ui(ui, |ui| {
+                        for algorithm in HullAlgorithm::ALL {
+                            ui.selectable_value(
+                                &mut self.selected_hull_algorithm,
+                                algorithm,
+                                algorithm.to_string(),
+                            );
+                        }
+                    });
+            }
+        });
+        ui.horizontal_wrapped(|ui| {
+            ui.add(egui::Slider::new(&mut self.line_width, 0.5..=10.0).text("Line width"));
+            ui.add(egui::Slider::new(&mut self.point_radius, 0.5..=20.0).text("Point radius"));
+            ui.checkbox(&mut self.show_labels, "Vertex labels");
+            if self.show_labels {
+                for kind in [LabelKind::Id, LabelKind::Index] {
+                    ui.radio_value(&mut self.label_kind, kind, kind.to_string());
+                }
+            }
         });
         ui.separator();
         
@@ -97,9 +238,23 @@ impl PolygonVisualizer {
             Visualization::Triangulation => {
                 self.draw_triangulation(ui, name)
             }
+            Visualization::ConvexHull => {
+                self.draw_convex_hull(ui, name)
+            }
+            Visualization::HullSteps => {
+                self.draw_hull_steps(ui, name)
+            }
         }
     }
 
+    fn hull_steps(&mut self, name: &String) -> &Vec<HullStep> {
+        // Monotone chain is the only computer that records its steps
+        let polygon = self.polygons.get(name).unwrap();
+        self.hull_steps
+            .entry(name.clone())
+            .or_insert_with(|| MonotoneChain.convex_hull_traced(polygon).1)
+    }
+
     fn draw_polygon(&self, ui: &mut egui::Ui, name: &String) -> Response {
         let plot = self.create_plot();
         let line = self.create_line(name);
@@ -108,12 +263,16 @@ impl PolygonVisualizer {
         plot.show(ui, |plot_ui| {
             plot_ui.line(line);
             plot_ui.points(points);
+            self.draw_labels(plot_ui, name);
         }).response
     }
 
     fn draw_triangulation(&self, ui: &mut egui::Ui, name: &String) -> Response {
+        let triangulation = match self.triangulations.get(name).unwrap() {
+            Ok(triangulation) => triangulation,
+            Err(e) => return ui.label(format!("Triangulation failed: {e}")),
+        };
         let plot = self.create_plot();
-        let triangulation = self.triangulations.get(name).unwrap();
         let triangles: Vec<_> = triangulation
             .iter()
             .map(|(p1, p2, p3)|
@@ -126,9 +285,88 @@ impl PolygonVisualizer {
             for triangle in triangles.into_iter() {
                 plot_ui.polygon(triangle);
             }
+            self.draw_labels(plot_ui, name);
+        }).response
+    }
+
+    fn draw_convex_hull(&self, ui: &mut egui::Ui, name: &String) -> Response {
+        let Some(hull) = self.hulls.get(&(name.clone(), self.selected_hull_algorithm)) else {
+            return ui.label("Convex hull is degenerate");
+        };
+        let plot = self.create_plot();
+        let line = self.create_line(name);
+        let points = self.create_points(name);
+        let hull = PlotPolygon::new(hull.clone())
+            .width(self.line_width)
+            .name(self.selected_hull_algorithm.to_string());
+
+        plot.show(ui, |plot_ui| {
+            plot_ui.line(line);
+            plot_ui.points(points);
+            plot_ui.polygon(hull);
+            self.draw_labels(plot_ui, name);
+        }).response
+    }
+
+    fn draw_hull_steps(&self, ui: &mut egui::Ui, name: &String) -> Response {
+        let steps = self.hull_steps.get(name).unwrap();
+        let to_plot = |points: &[Point]| points.iter().map(|p| [p.x, p.y]).collect::<Vec<_>>();
+        // Lower chain stays drawn while the upper chain is built
+        let completed: Vec<_> = steps[..self.selected_hull_step]
+            .iter()
+            .filter_map(|step| match step {
+                HullStep::ChainComplete { chain } => Some(to_plot(chain)),
+                _ => None,
+            })
+            .collect();
+        let (description, chain, highlighted) = match &steps[self.selected_hull_step] {
+            HullStep::Consider { candidate, chain } => {
+                ("Consider candidate", chain, vec![candidate])
+            }
+            HullStep::Pop { popped, candidate, chain } => {
+                ("Pop, no left turn to candidate", chain, vec![popped, candidate])
+            }
+            HullStep::Push { pushed, chain } => ("Push candidate", chain, vec![pushed]),
+            HullStep::ChainComplete { chain } => ("Half hull complete", chain, vec![]),
+        };
+        let highlighted: Vec<_> = highlighted.iter().map(|p| [p.x, p.y]).collect();
+        let chain = to_plot(chain);
+
+        ui.label(description);
+        let plot = self.create_plot();
+        let points = self.create_points(name);
+        plot.show(ui, |plot_ui| {
+            plot_ui.points(points);
+            for chain in completed.into_iter() {
+                plot_ui.line(Line::new(chain).width(self.line_width).name("Completed"));
+            }
+            plot_ui.line(Line::new(chain).width(self.line_width).name("Chain"));
+            plot_ui.points(
+                Points::new(highlighted)
+                    .radius(1.5 * self.point_radius)
+                    .name("Current")
+            );
+            self.draw_labels(plot_ui, name);
         }).response
     }
 
+    fn draw_labels(&self, plot_ui: &mut PlotUi, name: &String) {
+        if !self.show_labels {
+            return;
+        }
+        let points = self.points.get(name).unwrap();
+        let ids = self.vertex_ids.get(name).unwrap();
+        // Anchored at the bottom left so labels sit up and to the right
+        // of their point rather than covering it
+        for (index, (id, [x, y])) in ids.iter().zip(points.iter()).enumerate() {
+            let label = match self.label_kind {
+                LabelKind::Id => id.to_string(),
+                LabelKind::Index => index.to_string(),
+            };
+            plot_ui.text(Text::new(PlotPoint::new(*x, *y), label).anchor(Align2::LEFT_BOTTOM));
+        }
+    }
+
     fn create_plot(&self) -> Plot<'_> {
         Plot::new("polygon_visualizer")
             .show_axes(true)